@@ -0,0 +1,41 @@
+// This example shows how to wait for an answer of the user inside a handler,
+// without having to create channels through the state
+
+use panda::{events::MessageCreate, HandlerResult, Session};
+use std::{error::Error, time::Duration};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let mut client = panda::new("your token here").await?;
+
+    client.on_message_create(message_handler);
+    client.start().await?;
+
+    Ok(())
+}
+
+async fn message_handler(s: Session<()>, msg: MessageCreate) -> HandlerResult {
+    if msg.content != "!confirm" {
+        return Ok(());
+    }
+
+    msg.send(&s.http, "Reply `yes` to confirm").await?;
+
+    // Wait for the next message of the same author in the same channel
+    let author_id = msg.author.id.clone();
+    let channel_id = msg.channel_id.clone();
+    let answer = s
+        .wait_for::<MessageCreate>(
+            move |m| m.author.id == author_id && m.channel_id == channel_id,
+            Duration::from_secs(30),
+        )
+        .await;
+
+    match answer {
+        Some(m) if m.content == "yes" => msg.send(&s.http, "Confirmed!").await?,
+        Some(_) => msg.send(&s.http, "Cancelled").await?,
+        None => msg.send(&s.http, "You took too long to answer").await?,
+    };
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::models::{
+    channel::{Channel, Message},
+    guild::{Guild, GuildMember, Role},
+    user::Presence,
+    voice::VoiceState,
+};
+
+/// The storage used by the [`Cache`]. The cache keeps the data up to date with the received
+/// events, and the backend only saves it, so every method must be fast and can't fail, errors
+/// should be logged and handled as missing data.
+///
+/// Guilds are saved without channels, members, presences and voice states, because they are saved with their own
+/// methods, but `remove_guild` removes them too. Roles are part of the guild, but they can also be updated one
+/// by one, deleted roles are not removed from the members by the backend.
+///
+/// [`Cache`]: struct.Cache.html
+pub trait CacheBackend: Send + Sync {
+    // Guilds
+    fn upsert_guild(&self, guild: Guild);
+    /// Remove the guild with its channels and their messages, members, presences and voice states
+    fn remove_guild(&self, guild_id: &str) -> Option<Guild>;
+    fn guild(&self, guild_id: &str) -> Option<Guild>;
+    fn guild_ids(&self) -> Vec<String>;
+
+    // Channels
+    fn upsert_channel(&self, channel: Channel);
+    fn remove_channel(&self, channel_id: &str) -> Option<Channel>;
+    fn channel(&self, channel_id: &str) -> Option<Channel>;
+    fn guild_channels(&self, guild_id: &str) -> Vec<Channel>;
+
+    // Members
+    fn upsert_member(&self, guild_id: &str, member: GuildMember);
+    fn remove_member(&self, guild_id: &str, user_id: &str) -> Option<GuildMember>;
+    fn member(&self, guild_id: &str, user_id: &str) -> Option<GuildMember>;
+    fn guild_members(&self, guild_id: &str) -> Vec<GuildMember>;
+
+    // Roles
+    fn upsert_role(&self, guild_id: &str, role: Role);
+    fn remove_role(&self, guild_id: &str, role_id: &str) -> Option<Role>;
+    fn role(&self, guild_id: &str, role_id: &str) -> Option<Role>;
+    /// Returns the IDs of the roles of the guild, or `None` if the guild isn't cached
+    fn role_ids(&self, guild_id: &str) -> Option<Vec<String>> {
+        Some(self.guild(guild_id)?.roles.into_iter().map(|r| r.id).collect())
+    }
+
+    // Messages
+    fn upsert_message(&self, message: Message);
+    fn remove_message(&self, channel_id: &str, message_id: &str) -> Option<Message>;
+    fn message(&self, channel_id: &str, message_id: &str) -> Option<Message>;
+
+    // Presences
+    fn upsert_presence(&self, guild_id: &str, presence: Presence);
+    fn remove_presence(&self, guild_id: &str, user_id: &str) -> Option<Presence>;
+    fn presence(&self, guild_id: &str, user_id: &str) -> Option<Presence>;
+    fn guild_presences(&self, guild_id: &str) -> Vec<Presence>;
+
+    // Voice states
+    fn upsert_voice_state(&self, guild_id: &str, voice_state: VoiceState);
+    fn remove_voice_state(&self, guild_id: &str, user_id: &str) -> Option<VoiceState>;
+    fn voice_state(&self, guild_id: &str, user_id: &str) -> Option<VoiceState>;
+    fn guild_voice_states(&self, guild_id: &str) -> Vec<VoiceState>;
+
+    /// Save the changes that are not saved yet, it's called when the client shuts down
+    fn flush(&self) {}
+}
+
+/// A backend that doesn't save anything, for bots that don't need a cache
+#[derive(Default)]
+pub struct NoCacheBackend;
+
+impl CacheBackend for NoCacheBackend {
+    fn upsert_guild(&self, _guild: Guild) {}
+    fn remove_guild(&self, _guild_id: &str) -> Option<Guild> {
+        None
+    }
+    fn guild(&self, _guild_id: &str) -> Option<Guild> {
+        None
+    }
+    fn guild_ids(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn upsert_channel(&self, _channel: Channel) {}
+    fn remove_channel(&self, _channel_id: &str) -> Option<Channel> {
+        None
+    }
+    fn channel(&self, _channel_id: &str) -> Option<Channel> {
+        None
+    }
+    fn guild_channels(&self, _guild_id: &str) -> Vec<Channel> {
+        Vec::new()
+    }
+
+    fn upsert_member(&self, _guild_id: &str, _member: GuildMember) {}
+    fn remove_member(&self, _guild_id: &str, _user_id: &str) -> Option<GuildMember> {
+        None
+    }
+    fn member(&self, _guild_id: &str, _user_id: &str) -> Option<GuildMember> {
+        None
+    }
+    fn guild_members(&self, _guild_id: &str) -> Vec<GuildMember> {
+        Vec::new()
+    }
+
+    fn upsert_role(&self, _guild_id: &str, _role: Role) {}
+    fn remove_role(&self, _guild_id: &str, _role_id: &str) -> Option<Role> {
+        None
+    }
+    fn role(&self, _guild_id: &str, _role_id: &str) -> Option<Role> {
+        None
+    }
+
+    fn upsert_message(&self, _message: Message) {}
+    fn remove_message(&self, _channel_id: &str, _message_id: &str) -> Option<Message> {
+        None
+    }
+    fn message(&self, _channel_id: &str, _message_id: &str) -> Option<Message> {
+        None
+    }
+
+    fn upsert_presence(&self, _guild_id: &str, _presence: Presence) {}
+    fn remove_presence(&self, _guild_id: &str, _user_id: &str) -> Option<Presence> {
+        None
+    }
+    fn presence(&self, _guild_id: &str, _user_id: &str) -> Option<Presence> {
+        None
+    }
+    fn guild_presences(&self, _guild_id: &str) -> Vec<Presence> {
+        Vec::new()
+    }
+
+    fn upsert_voice_state(&self, _guild_id: &str, _voice_state: VoiceState) {}
+    fn remove_voice_state(&self, _guild_id: &str, _user_id: &str) -> Option<VoiceState> {
+        None
+    }
+    fn voice_state(&self, _guild_id: &str, _user_id: &str) -> Option<VoiceState> {
+        None
+    }
+    fn guild_voice_states(&self, _guild_id: &str) -> Vec<VoiceState> {
+        Vec::new()
+    }
+}
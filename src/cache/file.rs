@@ -0,0 +1,467 @@
+use super::{CacheBackend, MemoryBackend};
+use crate::models::{
+    channel::{Channel, Message},
+    guild::{Guild, GuildMember, Role},
+    user::Presence,
+    voice::VoiceState,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io, mem,
+    path::{Path, PathBuf},
+    sync::{Arc, Condvar, Mutex},
+    thread,
+    time::Duration,
+};
+
+// Time that the writer waits before saving the changes, so the changes of a burst of events,
+// like the members of a GUILD_CREATE, are saved together
+const BATCH_DELAY: Duration = Duration::from_millis(50);
+
+/// A backend that saves everything as JSON files in a directory, that way the cache is kept
+/// between restarts without any outside service.
+///
+/// The data is also kept in memory, so lookups never read the disk. The saved data is loaded when
+/// the backend is created, and the changes are saved by a background thread, in batches grouped by
+/// directory, so the events are never blocked by the disk. The pending changes are saved when the
+/// backend is dropped or when the client shuts down.
+///
+/// Cached messages are only kept in memory, their expiration is only known by the running cache.
+///
+/// The directory has the following layout:
+/// - `guilds/{guild_id}/guild.json`
+/// - `guilds/{guild_id}/channels/{channel_id}.json`
+/// - `guilds/{guild_id}/members/{user_id}.json`
+/// - `guilds/{guild_id}/presences/{user_id}.json`
+/// - `guilds/{guild_id}/voice_states/{user_id}.json`
+/// - `private/{channel_id}.json`
+pub struct FileBackend {
+    root: PathBuf,
+    memory: MemoryBackend,
+    writer: Arc<Writer>,
+}
+
+/// Changes that are not saved in the directory yet
+#[derive(Default)]
+struct Batch {
+    // path -> content, None when the file is removed
+    files: HashMap<PathBuf, Option<Vec<u8>>>,
+    // Directories removed with all their files, like the directory of a guild
+    removed_dirs: Vec<PathBuf>,
+}
+
+#[derive(Default)]
+struct Queue {
+    pending: Batch,
+    closed: bool,
+}
+
+#[derive(Default)]
+struct Writer {
+    queue: Mutex<Queue>,
+    changed: Condvar,
+    // Held while a batch is saved, so the batches are saved in order
+    saving: Mutex<()>,
+}
+
+impl FileBackend {
+    /// Create a new file backend in the given directory, it's created if it doesn't exist.
+    /// The data already saved in the directory is loaded, it blocks until it's read.
+    pub fn new(root: impl Into<PathBuf>) -> io::Result<Self> {
+        let root = root.into();
+
+        fs::create_dir_all(root.join("guilds"))?;
+        fs::create_dir_all(root.join("private"))?;
+
+        let memory = load(&root);
+
+        let writer = Arc::new(Writer::default());
+        let thread_writer = writer.clone();
+        thread::Builder::new()
+            .name("panda-file-cache".into())
+            .spawn(move || thread_writer.run())?;
+
+        Ok(FileBackend { root, memory, writer })
+    }
+
+    fn guild_dir(&self, guild_id: &str) -> PathBuf {
+        self.root.join("guilds").join(guild_id)
+    }
+
+    fn channel_path(&self, channel: &Channel) -> PathBuf {
+        match &channel.guild_id {
+            Some(guild_id) => self.guild_dir(guild_id).join("channels").join(json_name(&channel.id)),
+            None => self.root.join("private").join(json_name(&channel.id)),
+        }
+    }
+
+    fn write<T: Serialize>(&self, path: PathBuf, value: &T) {
+        match serde_json::to_vec(value) {
+            Ok(content) => self.writer.push(path, Some(content)),
+            Err(e) => log::error!("Cache couldn't serialize {}: {}", path.display(), e),
+        }
+    }
+
+    fn remove(&self, path: PathBuf) {
+        self.writer.push(path, None);
+    }
+
+    /// Save the guild with its current roles
+    fn save_guild(&self, guild_id: &str) {
+        if let Some(guild) = self.memory.guild(guild_id) {
+            self.write(self.guild_dir(guild_id).join("guild.json"), &guild);
+        }
+    }
+
+    /// Save the pending changes now, it blocks until they are saved
+    pub fn flush(&self) {
+        self.writer.save_pending();
+    }
+}
+
+impl Drop for FileBackend {
+    fn drop(&mut self) {
+        self.writer.queue.lock().unwrap().closed = true;
+        self.writer.changed.notify_one();
+        self.writer.save_pending();
+    }
+}
+
+impl Batch {
+    fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.removed_dirs.is_empty()
+    }
+
+    fn save(&self) {
+        for dir in &self.removed_dirs {
+            if let Err(e) = fs::remove_dir_all(dir) {
+                if e.kind() != io::ErrorKind::NotFound {
+                    log::error!("Cache couldn't remove {}: {}", dir.display(), e);
+                }
+            }
+        }
+
+        // Group the files by directory, each directory is created only once
+        let mut dirs: BTreeMap<&Path, Vec<&PathBuf>> = BTreeMap::new();
+        for path in self.files.keys() {
+            let dir = path.parent().unwrap_or_else(|| Path::new(""));
+            dirs.entry(dir).or_default().push(path);
+        }
+
+        for (dir, paths) in dirs {
+            if paths.iter().any(|path| self.files[*path].is_some()) {
+                if let Err(e) = fs::create_dir_all(dir) {
+                    log::error!("Cache couldn't create {}: {}", dir.display(), e);
+                    continue;
+                }
+            }
+
+            for path in paths {
+                match &self.files[path] {
+                    Some(content) => write_file(path, content),
+                    None => remove_file(path),
+                }
+            }
+        }
+    }
+}
+
+impl Writer {
+    fn push(&self, path: PathBuf, content: Option<Vec<u8>>) {
+        self.queue.lock().unwrap().pending.files.insert(path, content);
+        self.changed.notify_one();
+    }
+
+    /// Remove the directory with all its files, including the pending ones
+    fn remove_dir(&self, dir: PathBuf) {
+        let mut queue = self.queue.lock().unwrap();
+        queue.pending.files.retain(|path, _| !path.starts_with(&dir));
+        queue.pending.removed_dirs.push(dir);
+        drop(queue);
+
+        self.changed.notify_one();
+    }
+
+    fn save_pending(&self) {
+        let _saving = self.saving.lock().unwrap();
+
+        let batch = mem::take(&mut self.queue.lock().unwrap().pending);
+        if !batch.is_empty() {
+            batch.save();
+        }
+    }
+
+    /// Save the changes until the backend is dropped
+    fn run(&self) {
+        loop {
+            {
+                let mut queue = self.queue.lock().unwrap();
+                while queue.pending.is_empty() && !queue.closed {
+                    queue = self.changed.wait(queue).unwrap();
+                }
+
+                if queue.closed {
+                    return;
+                }
+            }
+
+            thread::sleep(BATCH_DELAY);
+            self.save_pending();
+        }
+    }
+}
+
+impl CacheBackend for FileBackend {
+    // Guilds
+    fn upsert_guild(&self, guild: Guild) {
+        self.write(self.guild_dir(&guild.id).join("guild.json"), &guild);
+        self.memory.upsert_guild(guild);
+    }
+
+    fn remove_guild(&self, guild_id: &str) -> Option<Guild> {
+        self.writer.remove_dir(self.guild_dir(guild_id));
+        self.memory.remove_guild(guild_id)
+    }
+
+    fn guild(&self, guild_id: &str) -> Option<Guild> {
+        self.memory.guild(guild_id)
+    }
+
+    fn guild_ids(&self) -> Vec<String> {
+        self.memory.guild_ids()
+    }
+
+    // Channels
+    fn upsert_channel(&self, channel: Channel) {
+        self.write(self.channel_path(&channel), &channel);
+        self.memory.upsert_channel(channel);
+    }
+
+    fn remove_channel(&self, channel_id: &str) -> Option<Channel> {
+        let channel = self.memory.remove_channel(channel_id)?;
+        self.remove(self.channel_path(&channel));
+
+        Some(channel)
+    }
+
+    fn channel(&self, channel_id: &str) -> Option<Channel> {
+        self.memory.channel(channel_id)
+    }
+
+    fn guild_channels(&self, guild_id: &str) -> Vec<Channel> {
+        self.memory.guild_channels(guild_id)
+    }
+
+    // Members
+    fn upsert_member(&self, guild_id: &str, member: GuildMember) {
+        // Members without user can't be indexed
+        let user_id = match &member.user {
+            Some(user) => user.id.clone(),
+            None => return,
+        };
+
+        let path = self.guild_dir(guild_id).join("members").join(json_name(&user_id));
+        self.write(path, &member);
+        self.memory.upsert_member(guild_id, member);
+    }
+
+    fn remove_member(&self, guild_id: &str, user_id: &str) -> Option<GuildMember> {
+        self.remove(self.guild_dir(guild_id).join("members").join(json_name(user_id)));
+        self.memory.remove_member(guild_id, user_id)
+    }
+
+    fn member(&self, guild_id: &str, user_id: &str) -> Option<GuildMember> {
+        self.memory.member(guild_id, user_id)
+    }
+
+    fn guild_members(&self, guild_id: &str) -> Vec<GuildMember> {
+        self.memory.guild_members(guild_id)
+    }
+
+    // Roles
+    fn upsert_role(&self, guild_id: &str, role: Role) {
+        self.memory.upsert_role(guild_id, role);
+        self.save_guild(guild_id);
+    }
+
+    fn remove_role(&self, guild_id: &str, role_id: &str) -> Option<Role> {
+        let role = self.memory.remove_role(guild_id, role_id)?;
+        self.save_guild(guild_id);
+
+        Some(role)
+    }
+
+    fn role(&self, guild_id: &str, role_id: &str) -> Option<Role> {
+        self.memory.role(guild_id, role_id)
+    }
+
+    fn role_ids(&self, guild_id: &str) -> Option<Vec<String>> {
+        self.memory.role_ids(guild_id)
+    }
+
+    // Messages
+    fn upsert_message(&self, message: Message) {
+        self.memory.upsert_message(message);
+    }
+
+    fn remove_message(&self, channel_id: &str, message_id: &str) -> Option<Message> {
+        self.memory.remove_message(channel_id, message_id)
+    }
+
+    fn message(&self, channel_id: &str, message_id: &str) -> Option<Message> {
+        self.memory.message(channel_id, message_id)
+    }
+
+    // Presences
+    fn upsert_presence(&self, guild_id: &str, presence: Presence) {
+        let path = self
+            .guild_dir(guild_id)
+            .join("presences")
+            .join(json_name(&presence.user_id));
+        self.write(path, &presence);
+        self.memory.upsert_presence(guild_id, presence);
+    }
+
+    fn remove_presence(&self, guild_id: &str, user_id: &str) -> Option<Presence> {
+        self.remove(self.guild_dir(guild_id).join("presences").join(json_name(user_id)));
+        self.memory.remove_presence(guild_id, user_id)
+    }
+
+    fn presence(&self, guild_id: &str, user_id: &str) -> Option<Presence> {
+        self.memory.presence(guild_id, user_id)
+    }
+
+    fn guild_presences(&self, guild_id: &str) -> Vec<Presence> {
+        self.memory.guild_presences(guild_id)
+    }
+
+    // Voice states
+    fn upsert_voice_state(&self, guild_id: &str, voice_state: VoiceState) {
+        let path = self
+            .guild_dir(guild_id)
+            .join("voice_states")
+            .join(json_name(&voice_state.user_id));
+        self.write(path, &voice_state);
+        self.memory.upsert_voice_state(guild_id, voice_state);
+    }
+
+    fn remove_voice_state(&self, guild_id: &str, user_id: &str) -> Option<VoiceState> {
+        self.remove(self.guild_dir(guild_id).join("voice_states").join(json_name(user_id)));
+        self.memory.remove_voice_state(guild_id, user_id)
+    }
+
+    fn voice_state(&self, guild_id: &str, user_id: &str) -> Option<VoiceState> {
+        self.memory.voice_state(guild_id, user_id)
+    }
+
+    fn guild_voice_states(&self, guild_id: &str) -> Vec<VoiceState> {
+        self.memory.guild_voice_states(guild_id)
+    }
+
+    fn flush(&self) {
+        FileBackend::flush(self);
+    }
+}
+
+/// Read all the data saved in the directory
+fn load(root: &Path) -> MemoryBackend {
+    let memory = MemoryBackend::new();
+
+    for guild_id in list_dir(&root.join("guilds")) {
+        let dir = root.join("guilds").join(&guild_id);
+        let guild = match read_json::<Guild>(&dir.join("guild.json")) {
+            Some(guild) => guild,
+            None => continue,
+        };
+        memory.upsert_guild(guild);
+
+        for channel in read_all::<Channel>(&dir.join("channels")) {
+            memory.upsert_channel(channel);
+        }
+        for member in read_all(&dir.join("members")) {
+            memory.upsert_member(&guild_id, member);
+        }
+        for presence in read_all(&dir.join("presences")) {
+            memory.upsert_presence(&guild_id, presence);
+        }
+        for voice_state in read_all(&dir.join("voice_states")) {
+            memory.upsert_voice_state(&guild_id, voice_state);
+        }
+    }
+
+    for channel in read_all::<Channel>(&root.join("private")) {
+        memory.upsert_channel(channel);
+    }
+
+    memory
+}
+
+fn json_name(id: &str) -> String {
+    format!("{}.json", id)
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let bytes = fs::read(path).ok()?;
+
+    match serde_json::from_slice(&bytes) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            log::error!("Cache couldn't read {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Read all json files of a directory
+fn read_all<T: DeserializeOwned>(dir: &Path) -> Vec<T> {
+    list_json(dir)
+        .into_iter()
+        .filter_map(|name| read_json(&dir.join(json_name(&name))))
+        .collect()
+}
+
+/// Write the file, first it's written to a temporary file and then renamed, that way the file
+/// is never half written
+fn write_file(path: &Path, content: &[u8]) {
+    let tmp = path.with_extension("json.tmp");
+
+    if let Err(e) = fs::write(&tmp, content).and_then(|_| fs::rename(&tmp, path)) {
+        log::error!("Cache couldn't write {}: {}", path.display(), e);
+    }
+}
+
+fn remove_file(path: &Path) {
+    if let Err(e) = fs::remove_file(path) {
+        if e.kind() != io::ErrorKind::NotFound {
+            log::error!("Cache couldn't remove {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Returns the names of all directories inside the directory
+fn list_dir(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| e.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the names without extension of all json files inside the directory
+fn list_json(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| e.file_name().into_string().ok())
+                .filter_map(|name| name.strip_suffix(".json").map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,228 @@
+use super::CacheBackend;
+use crate::models::{
+    channel::{Channel, Message},
+    guild::{Guild, GuildMember, Role},
+    user::Presence,
+    voice::VoiceState,
+};
+
+use std::{collections::HashMap, sync::RwLock};
+
+/// A backend that saves everything in memory, it's the default backend
+#[derive(Default)]
+pub struct MemoryBackend {
+    guilds: RwLock<HashMap<String, Guild>>,
+    channels: RwLock<HashMap<String, Channel>>,
+    // guild_id -> user_id -> member
+    members: RwLock<HashMap<String, HashMap<String, GuildMember>>>,
+    // channel_id -> message_id -> message
+    messages: RwLock<HashMap<String, HashMap<String, Message>>>,
+    // guild_id -> user_id -> presence
+    presences: RwLock<HashMap<String, HashMap<String, Presence>>>,
+    // guild_id -> user_id -> voice state
+    voice_states: RwLock<HashMap<String, HashMap<String, VoiceState>>>,
+}
+
+impl MemoryBackend {
+    pub fn new() -> Self {
+        MemoryBackend::default()
+    }
+}
+
+impl CacheBackend for MemoryBackend {
+    // Guilds
+    fn upsert_guild(&self, guild: Guild) {
+        self.guilds.write().unwrap().insert(guild.id.clone(), guild);
+    }
+
+    fn remove_guild(&self, guild_id: &str) -> Option<Guild> {
+        let mut channels = self.channels.write().unwrap();
+        let mut messages = self.messages.write().unwrap();
+        channels.retain(|channel_id, channel| {
+            if channel.guild_id.as_deref() != Some(guild_id) {
+                return true;
+            }
+
+            messages.remove(channel_id);
+            false
+        });
+
+        self.members.write().unwrap().remove(guild_id);
+        self.presences.write().unwrap().remove(guild_id);
+        self.voice_states.write().unwrap().remove(guild_id);
+
+        self.guilds.write().unwrap().remove(guild_id)
+    }
+
+    fn guild(&self, guild_id: &str) -> Option<Guild> {
+        self.guilds.read().unwrap().get(guild_id).cloned()
+    }
+
+    fn guild_ids(&self) -> Vec<String> {
+        self.guilds.read().unwrap().keys().cloned().collect()
+    }
+
+    // Channels
+    fn upsert_channel(&self, channel: Channel) {
+        self.channels.write().unwrap().insert(channel.id.clone(), channel);
+    }
+
+    fn remove_channel(&self, channel_id: &str) -> Option<Channel> {
+        self.messages.write().unwrap().remove(channel_id);
+        self.channels.write().unwrap().remove(channel_id)
+    }
+
+    fn channel(&self, channel_id: &str) -> Option<Channel> {
+        self.channels.read().unwrap().get(channel_id).cloned()
+    }
+
+    fn guild_channels(&self, guild_id: &str) -> Vec<Channel> {
+        self.channels
+            .read()
+            .unwrap()
+            .values()
+            .filter(|c| c.guild_id.as_deref() == Some(guild_id))
+            .cloned()
+            .collect()
+    }
+
+    // Members
+    fn upsert_member(&self, guild_id: &str, member: GuildMember) {
+        // Members without user can't be indexed
+        let user_id = match &member.user {
+            Some(user) => user.id.clone(),
+            None => return,
+        };
+
+        self.members
+            .write()
+            .unwrap()
+            .entry(guild_id.to_owned())
+            .or_default()
+            .insert(user_id, member);
+    }
+
+    fn remove_member(&self, guild_id: &str, user_id: &str) -> Option<GuildMember> {
+        self.members.write().unwrap().get_mut(guild_id)?.remove(user_id)
+    }
+
+    fn member(&self, guild_id: &str, user_id: &str) -> Option<GuildMember> {
+        self.members.read().unwrap().get(guild_id)?.get(user_id).cloned()
+    }
+
+    fn guild_members(&self, guild_id: &str) -> Vec<GuildMember> {
+        self.members
+            .read()
+            .unwrap()
+            .get(guild_id)
+            .map(|members| members.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Roles
+    fn upsert_role(&self, guild_id: &str, role: Role) {
+        if let Some(guild) = self.guilds.write().unwrap().get_mut(guild_id) {
+            match guild.roles.iter_mut().find(|r| r.id == role.id) {
+                Some(old) => *old = role,
+                None => guild.roles.push(role),
+            }
+        }
+    }
+
+    fn remove_role(&self, guild_id: &str, role_id: &str) -> Option<Role> {
+        let mut guilds = self.guilds.write().unwrap();
+        let roles = &mut guilds.get_mut(guild_id)?.roles;
+        let index = roles.iter().position(|r| r.id == role_id)?;
+
+        Some(roles.remove(index))
+    }
+
+    fn role(&self, guild_id: &str, role_id: &str) -> Option<Role> {
+        self.guilds
+            .read()
+            .unwrap()
+            .get(guild_id)?
+            .roles
+            .iter()
+            .find(|r| r.id == role_id)
+            .cloned()
+    }
+
+    fn role_ids(&self, guild_id: &str) -> Option<Vec<String>> {
+        let guilds = self.guilds.read().unwrap();
+
+        Some(guilds.get(guild_id)?.roles.iter().map(|r| r.id.clone()).collect())
+    }
+
+    // Messages
+    fn upsert_message(&self, message: Message) {
+        self.messages
+            .write()
+            .unwrap()
+            .entry(message.channel_id.clone())
+            .or_default()
+            .insert(message.id.clone(), message);
+    }
+
+    fn remove_message(&self, channel_id: &str, message_id: &str) -> Option<Message> {
+        self.messages.write().unwrap().get_mut(channel_id)?.remove(message_id)
+    }
+
+    fn message(&self, channel_id: &str, message_id: &str) -> Option<Message> {
+        self.messages.read().unwrap().get(channel_id)?.get(message_id).cloned()
+    }
+
+    // Presences
+    fn upsert_presence(&self, guild_id: &str, presence: Presence) {
+        self.presences
+            .write()
+            .unwrap()
+            .entry(guild_id.to_owned())
+            .or_default()
+            .insert(presence.user_id.clone(), presence);
+    }
+
+    fn remove_presence(&self, guild_id: &str, user_id: &str) -> Option<Presence> {
+        self.presences.write().unwrap().get_mut(guild_id)?.remove(user_id)
+    }
+
+    fn presence(&self, guild_id: &str, user_id: &str) -> Option<Presence> {
+        self.presences.read().unwrap().get(guild_id)?.get(user_id).cloned()
+    }
+
+    fn guild_presences(&self, guild_id: &str) -> Vec<Presence> {
+        self.presences
+            .read()
+            .unwrap()
+            .get(guild_id)
+            .map(|presences| presences.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Voice states
+    fn upsert_voice_state(&self, guild_id: &str, voice_state: VoiceState) {
+        self.voice_states
+            .write()
+            .unwrap()
+            .entry(guild_id.to_owned())
+            .or_default()
+            .insert(voice_state.user_id.clone(), voice_state);
+    }
+
+    fn remove_voice_state(&self, guild_id: &str, user_id: &str) -> Option<VoiceState> {
+        self.voice_states.write().unwrap().get_mut(guild_id)?.remove(user_id)
+    }
+
+    fn voice_state(&self, guild_id: &str, user_id: &str) -> Option<VoiceState> {
+        self.voice_states.read().unwrap().get(guild_id)?.get(user_id).cloned()
+    }
+
+    fn guild_voice_states(&self, guild_id: &str) -> Vec<VoiceState> {
+        self.voice_states
+            .read()
+            .unwrap()
+            .get(guild_id)
+            .map(|voice_states| voice_states.values().cloned().collect())
+            .unwrap_or_default()
+    }
+}
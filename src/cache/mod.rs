@@ -0,0 +1,668 @@
+//! # Panda Cache
+//!
+//! Cache of guilds, channels, roles, members, presences and voice states. It's updated by the
+//! client with every received event, before calling the handlers, and it's reachable as
+//! `session.cache`.
+//!
+//! The data is saved in a [`CacheBackend`], by default it's saved in memory with
+//! [`MemoryBackend`]. It can be changed with [`ConfigBuilder::set_cache_backend`].
+//!
+//! Optionally it can also save the last messages of every channel, it's disabled by default
+//! and can be enabled with [`ConfigBuilder::set_message_cache`].
+//!
+//! [`CacheBackend`]: trait.CacheBackend.html
+//! [`MemoryBackend`]: struct.MemoryBackend.html
+//! [`ConfigBuilder::set_cache_backend`]: ../client/config/struct.ConfigBuilder.html#method.set_cache_backend
+//! [`ConfigBuilder::set_message_cache`]: ../client/config/struct.ConfigBuilder.html#method.set_message_cache
+
+mod backend;
+mod file;
+mod memory;
+mod snapshot;
+mod tracker;
+
+#[cfg(test)]
+mod tests;
+
+pub use backend::{CacheBackend, NoCacheBackend};
+pub use file::FileBackend;
+pub use memory::MemoryBackend;
+pub use snapshot::CacheSnapshot;
+pub use tracker::CacheReady;
+pub(crate) use tracker::GuildStatus;
+
+use tracker::GuildTracker;
+
+use crate::models::{
+    channel::{Channel, Message},
+    gateway::events::DispatchEvent,
+    guild::{Guild, GuildMember, Role},
+    user::{ActivityKind, Presence},
+    voice::VoiceState,
+};
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+/// The cache of the current session. All lookups return a copy of the cached value.
+pub struct Cache {
+    backend: RwLock<Arc<dyn CacheBackend>>,
+    // channel_id -> (message_id, inserted_at), the least recently used are at the front
+    message_index: RwLock<HashMap<String, VecDeque<(String, Instant)>>>,
+    message_limits: RwLock<Option<MessageLimits>>,
+    guild_tracker: RwLock<GuildTracker>,
+}
+
+#[derive(Clone, Copy)]
+struct MessageLimits {
+    max_per_channel: usize,
+    ttl: Duration,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Cache {
+            backend: RwLock::new(Arc::new(MemoryBackend::new())),
+            message_index: RwLock::default(),
+            message_limits: RwLock::default(),
+            guild_tracker: RwLock::default(),
+        }
+    }
+}
+
+impl Cache {
+    pub(crate) fn new() -> Self {
+        Cache::default()
+    }
+
+    /// Replace the backend where the data is saved
+    pub(crate) fn set_backend(&self, backend: Arc<dyn CacheBackend>) {
+        *self.backend.write().unwrap() = backend;
+        self.message_index.write().unwrap().clear();
+    }
+
+    /// Enable the message cache, with the max number of messages saved per channel and
+    /// the time that each message is kept
+    pub(crate) fn set_message_limits(&self, max_per_channel: usize, ttl: Duration) {
+        *self.message_limits.write().unwrap() = Some(MessageLimits { max_per_channel, ttl });
+    }
+
+    /// Returns the current backend
+    pub fn backend(&self) -> Arc<dyn CacheBackend> {
+        self.backend.read().unwrap().clone()
+    }
+
+    // *******************************************************************************
+    // * LOOKUPS
+    // *******************************************************************************
+
+    /// Returns the [`Guild`] with the given ID. The returned guild doesn't contain channels and
+    /// members, use [`guild_channels`] and [`guild_members`] instead.
+    ///
+    /// [`Guild`]: ../models/guild/struct.Guild.html
+    /// [`guild_channels`]: #method.guild_channels
+    /// [`guild_members`]: #method.guild_members
+    pub fn guild(&self, guild_id: impl AsRef<str>) -> Option<Guild> {
+        self.backend().guild(guild_id.as_ref())
+    }
+
+    /// Returns the IDs of all cached guilds
+    pub fn guild_ids(&self) -> Vec<String> {
+        self.backend().guild_ids()
+    }
+
+    /// Returns the IDs of the guilds that are unavailable, because of an outage or
+    /// because they have not been received yet after the [`Ready`] event
+    ///
+    /// [`Ready`]: ../models/gateway/events/struct.Ready.html
+    pub fn unavailable_guild_ids(&self) -> Vec<String> {
+        self.guild_tracker.read().unwrap().unavailable_ids()
+    }
+
+    /// Returns true if the guild is unavailable
+    pub fn is_guild_unavailable(&self, guild_id: impl AsRef<str>) -> bool {
+        self.guild_tracker.read().unwrap().is_unavailable(guild_id.as_ref())
+    }
+
+    /// Returns true when all guilds of the [`Ready`] event have been received
+    ///
+    /// [`Ready`]: ../models/gateway/events/struct.Ready.html
+    pub fn is_ready(&self) -> bool {
+        self.guild_tracker.read().unwrap().is_ready()
+    }
+
+    /// Returns the [`Channel`] with the given ID
+    ///
+    /// [`Channel`]: ../models/channel/struct.Channel.html
+    pub fn channel(&self, channel_id: impl AsRef<str>) -> Option<Channel> {
+        self.backend().channel(channel_id.as_ref())
+    }
+
+    /// Returns all cached channels of a guild
+    pub fn guild_channels(&self, guild_id: impl AsRef<str>) -> Vec<Channel> {
+        self.backend().guild_channels(guild_id.as_ref())
+    }
+
+    /// Returns the [`GuildMember`] of the user in the guild
+    ///
+    /// [`GuildMember`]: ../models/guild/struct.GuildMember.html
+    pub fn member(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Option<GuildMember> {
+        let guild_id = guild_id.as_ref();
+        let mut member = self.backend().member(guild_id, user_id.as_ref())?;
+        self.remove_deleted_roles(guild_id, std::slice::from_mut(&mut member));
+
+        Some(member)
+    }
+
+    /// Returns all cached members of a guild
+    pub fn guild_members(&self, guild_id: impl AsRef<str>) -> Vec<GuildMember> {
+        let guild_id = guild_id.as_ref();
+        let mut members = self.backend().guild_members(guild_id);
+        self.remove_deleted_roles(guild_id, &mut members);
+
+        members
+    }
+
+    /// Remove the roles that don't exist anymore from the members, when a role is deleted the saved
+    /// members are not updated, that would rewrite every member of the guild
+    fn remove_deleted_roles(&self, guild_id: &str, members: &mut [GuildMember]) {
+        let role_ids = match self.backend().role_ids(guild_id) {
+            Some(role_ids) => role_ids,
+            None => return,
+        };
+
+        for member in members {
+            member.roles.retain(|id| role_ids.contains(id));
+        }
+    }
+
+    /// Returns the [`Role`] of the guild with the given ID
+    ///
+    /// [`Role`]: ../models/guild/struct.Role.html
+    pub fn role(&self, guild_id: impl AsRef<str>, role_id: impl AsRef<str>) -> Option<Role> {
+        self.backend().role(guild_id.as_ref(), role_id.as_ref())
+    }
+
+    /// Returns all roles of a guild
+    pub fn roles(&self, guild_id: impl AsRef<str>) -> Vec<Role> {
+        self.guild(guild_id).map(|g| g.roles).unwrap_or_default()
+    }
+
+    /// Returns the latest [`Presence`] of the user in the guild. Offline users don't have a presence.
+    ///
+    /// [`Presence`]: ../models/user/struct.Presence.html
+    pub fn presence(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Option<Presence> {
+        self.backend().presence(guild_id.as_ref(), user_id.as_ref())
+    }
+
+    /// Returns the presences of all online users of a guild
+    pub fn guild_presences(&self, guild_id: impl AsRef<str>) -> Vec<Presence> {
+        self.backend().guild_presences(guild_id.as_ref())
+    }
+
+    /// Returns the presences of the users of a guild that are currently streaming
+    pub fn streaming_presences(&self, guild_id: impl AsRef<str>) -> Vec<Presence> {
+        self.guild_presences(guild_id)
+            .into_iter()
+            .filter(|p| p.activities.iter().any(|a| a.kind == ActivityKind::Streaming))
+            .collect()
+    }
+
+    /// Returns the [`VoiceState`] of the user in the guild, if the user is in a voice channel
+    ///
+    /// [`VoiceState`]: ../models/voice/struct.VoiceState.html
+    pub fn voice_state(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Option<VoiceState> {
+        self.backend().voice_state(guild_id.as_ref(), user_id.as_ref())
+    }
+
+    /// Returns the voice states of all users that are in a voice channel of the guild
+    pub fn guild_voice_states(&self, guild_id: impl AsRef<str>) -> Vec<VoiceState> {
+        self.backend().guild_voice_states(guild_id.as_ref())
+    }
+
+    /// Returns the voice states of all users that are in the voice channel
+    pub fn voice_channel_states(&self, channel_id: impl AsRef<str>) -> Vec<VoiceState> {
+        let channel_id = channel_id.as_ref();
+        let guild_id = match self.channel(channel_id).and_then(|c| c.guild_id) {
+            Some(guild_id) => guild_id,
+            None => return Vec::new(),
+        };
+
+        self.guild_voice_states(guild_id)
+            .into_iter()
+            .filter(|v| v.channel_id.as_deref() == Some(channel_id))
+            .collect()
+    }
+
+    /// Returns the [`Message`] if it's in the message cache, it becomes the most recently used
+    /// message of the channel
+    ///
+    /// [`Message`]: ../models/channel/struct.Message.html
+    pub fn message(&self, channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> Option<Message> {
+        let (channel_id, message_id) = (channel_id.as_ref(), message_id.as_ref());
+        let ttl = self.message_limits.read().unwrap().as_ref()?.ttl;
+
+        {
+            let mut index = self.message_index.write().unwrap();
+            let channel = index.get_mut(channel_id)?;
+            let position = channel.iter().position(|(id, _)| id == message_id)?;
+
+            if channel[position].1.elapsed() >= ttl {
+                return None;
+            }
+
+            // The TTL still counts from the insertion
+            let entry = channel.remove(position)?;
+            channel.push_back(entry);
+        }
+
+        self.backend().message(channel_id, message_id)
+    }
+
+    /// Returns all messages of the channel that are in the message cache, from oldest to newest
+    pub fn channel_messages(&self, channel_id: impl AsRef<str>) -> Vec<Message> {
+        let channel_id = channel_id.as_ref();
+        let ttl = match self.message_limits.read().unwrap().as_ref() {
+            Some(limits) => limits.ttl,
+            None => return Vec::new(),
+        };
+
+        let mut ids: Vec<String> = match self.message_index.read().unwrap().get(channel_id) {
+            Some(index) => index
+                .iter()
+                .filter(|(_, inserted_at)| inserted_at.elapsed() < ttl)
+                .map(|(id, _)| id.clone())
+                .collect(),
+            None => return Vec::new(),
+        };
+
+        // The index is sorted by use, the IDs are snowflakes so they are sorted by creation time
+        ids.sort_by_key(|id| id.parse::<u64>().unwrap_or_default());
+
+        let backend = self.backend();
+        ids.iter().filter_map(|id| backend.message(channel_id, id)).collect()
+    }
+
+    // *******************************************************************************
+    // * SNAPSHOTS
+    // *******************************************************************************
+
+    /// Returns a [`CacheSnapshot`] with all cached guilds, it doesn't contain the session of
+    /// the gateway
+    ///
+    /// [`CacheSnapshot`]: struct.CacheSnapshot.html
+    pub fn export(&self) -> CacheSnapshot {
+        let backend = self.backend();
+
+        let guilds = backend
+            .guild_ids()
+            .into_iter()
+            .filter_map(|id| {
+                let mut guild = backend.guild(&id)?;
+                guild.channels = backend.guild_channels(&id);
+                guild.members = self.guild_members(&id);
+                guild.presences = backend.guild_presences(&id);
+                guild.voice_states = backend.guild_voice_states(&id);
+
+                Some(guild)
+            })
+            .collect();
+
+        CacheSnapshot {
+            session_id: None,
+            sequence: None,
+            guilds,
+            unavailable_guild_ids: self.unavailable_guild_ids(),
+        }
+    }
+
+    /// Save all guilds of the [`CacheSnapshot`] in the cache. Entries that changed since the
+    /// snapshot was created will be updated by the next events.
+    ///
+    /// [`CacheSnapshot`]: struct.CacheSnapshot.html
+    pub fn import(&self, snapshot: CacheSnapshot) {
+        for guild in snapshot.guilds {
+            self.insert_guild(guild);
+        }
+
+        self.guild_tracker
+            .write()
+            .unwrap()
+            .restore(snapshot.unavailable_guild_ids);
+    }
+
+    // *******************************************************************************
+    // * UPDATES
+    // *******************************************************************************
+
+    /// Track the availability of the guilds, it returns the status change of the guild
+    /// of `GUILD_CREATE` and `GUILD_DELETE` events
+    pub(crate) fn track_guild(&self, event: &DispatchEvent) -> Option<GuildStatus> {
+        self.guild_tracker.write().unwrap().update(event)
+    }
+
+    /// Returns [`CacheReady`] only once, when all guilds of the `READY` event were received
+    ///
+    /// [`CacheReady`]: struct.CacheReady.html
+    pub(crate) fn take_ready(&self) -> Option<CacheReady> {
+        let mut tracker = self.guild_tracker.write().unwrap();
+        if !tracker.take_ready() {
+            return None;
+        }
+
+        let unavailable_guild_ids = tracker.unavailable_ids();
+        let guild_ids = self
+            .guild_ids()
+            .into_iter()
+            .filter(|id| !unavailable_guild_ids.contains(id))
+            .collect();
+
+        Some(CacheReady {
+            guild_ids,
+            unavailable_guild_ids,
+        })
+    }
+
+    /// Update the cache with the event. Message update and delete events are filled with
+    /// the cached message, if there is one.
+    pub(crate) fn update(&self, event: &mut DispatchEvent) {
+        let backend = self.backend();
+
+        match event {
+            // Guilds that the user is not in anymore, like the ones of an old snapshot
+            DispatchEvent::Ready(e) => {
+                for guild_id in backend.guild_ids() {
+                    if !e.guilds.iter().any(|g| g.id == guild_id) {
+                        self.remove_guild(&guild_id);
+                    }
+                }
+            }
+
+            // Guild
+            DispatchEvent::GuildCreate(e) => self.insert_guild(e.0.clone()),
+            DispatchEvent::GuildUpdate(e) => self.update_guild(e.0.clone()),
+            DispatchEvent::GuildDelete(e) => {
+                if e.unavailable {
+                    if let Some(mut guild) = backend.guild(&e.id) {
+                        guild.unavailable = true;
+                        backend.upsert_guild(guild);
+                    }
+                } else {
+                    self.remove_guild(&e.id);
+                }
+            }
+            DispatchEvent::GuildEmojisUpdate(e) => {
+                if let Some(mut guild) = backend.guild(&e.guild_id) {
+                    guild.emojis = e.emojis.clone();
+                    backend.upsert_guild(guild);
+                }
+            }
+
+            // Channel
+            DispatchEvent::ChannelCreate(e) => backend.upsert_channel(e.0.clone()),
+            DispatchEvent::ChannelUpdate(e) => backend.upsert_channel(e.0.clone()),
+            DispatchEvent::ChannelDelete(e) => self.remove_channel(&e.id),
+
+            // Message
+            DispatchEvent::MessageCreate(e) => self.insert_message(e.0.clone()),
+            DispatchEvent::MessageUpdate(e) => {
+                e.old_message = self.remove_message(&e.channel_id, &e.id);
+
+                // Save the updated message
+                if let Some(mut message) = e.old_message.clone() {
+                    if let Some(content) = &e.content {
+                        message.content = content.clone();
+                    }
+                    if e.edited_timestamp.is_some() {
+                        message.edited_timestamp = e.edited_timestamp.clone();
+                    }
+                    if let Some(embeds) = &e.embeds {
+                        message.embed = embeds.clone();
+                    }
+
+                    self.insert_message(message);
+                }
+            }
+            DispatchEvent::MessageDelete(e) => {
+                e.old_message = self.remove_message(&e.channel_id, &e.id);
+            }
+            DispatchEvent::MessageDeleteBulk(e) => {
+                let channel_id = &e.channel_id;
+                e.old_messages = e
+                    .ids
+                    .iter()
+                    .filter_map(|id| self.remove_message(channel_id, id))
+                    .collect();
+            }
+
+            // Member
+            DispatchEvent::GuildMemberAdd(e) => {
+                if let Some(guild_id) = &e.guild_id {
+                    backend.upsert_member(guild_id, e.0.clone());
+
+                    if let Some(mut guild) = backend.guild(guild_id) {
+                        guild.member_count = guild.member_count.map(|c| c + 1);
+                        backend.upsert_guild(guild);
+                    }
+                }
+            }
+            DispatchEvent::GuildMemberUpdate(e) => {
+                let member = match backend.member(&e.guild_id, &e.user.id) {
+                    Some(mut member) => {
+                        member.user = Some(e.user.clone());
+                        member.nick = e.nick.clone();
+                        member.roles = e.roles.clone();
+                        member.premium_since = e.premium_since.clone();
+                        member
+                    }
+                    None => GuildMember {
+                        user: Some(e.user.clone()),
+                        nick: e.nick.clone(),
+                        roles: e.roles.clone(),
+                        joined_at: None,
+                        premium_since: e.premium_since.clone(),
+                        deaf: false,
+                        mute: false,
+                        guild_id: None,
+                    },
+                };
+
+                backend.upsert_member(&e.guild_id, member);
+            }
+            DispatchEvent::GuildMemberRemove(e) => {
+                backend.remove_member(&e.guild_id, &e.user.id);
+
+                if let Some(mut guild) = backend.guild(&e.guild_id) {
+                    guild.member_count = guild.member_count.map(|c| c.saturating_sub(1));
+                    backend.upsert_guild(guild);
+                }
+            }
+            DispatchEvent::GuildMembersChunk(e) => {
+                for member in &e.members {
+                    backend.upsert_member(&e.guild_id, member.clone());
+                }
+
+                for presence in &e.presences {
+                    self.insert_presence(&e.guild_id, presence.clone());
+                }
+            }
+
+            // Presence
+            DispatchEvent::PresenceUpdate(e) => {
+                let presence = Presence {
+                    user_id: e.user_id.clone(),
+                    game: e.game.clone(),
+                    status: e.status.clone(),
+                    activities: e.activities.clone(),
+                };
+
+                self.insert_presence(&e.guild_id, presence);
+            }
+
+            // Voice
+            DispatchEvent::VoiceStateUpdate(e) => {
+                if let Some(guild_id) = &e.guild_id {
+                    self.insert_voice_state(guild_id, e.0.clone());
+                }
+            }
+
+            // Role
+            DispatchEvent::GuildRoleCreate(e) => backend.upsert_role(&e.guild_id, e.role.clone()),
+            DispatchEvent::GuildRoleUpdate(e) => backend.upsert_role(&e.guild_id, e.role.clone()),
+            DispatchEvent::GuildRoleDelete(e) => {
+                // The saved members keep the role, it's removed when they are read
+                backend.remove_role(&e.guild_id, &e.role_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn insert_guild(&self, mut guild: Guild) {
+        let backend = self.backend();
+        let channels = std::mem::take(&mut guild.channels);
+        let members = std::mem::take(&mut guild.members);
+        let presences = std::mem::take(&mut guild.presences);
+        let voice_states = std::mem::take(&mut guild.voice_states);
+
+        // Channels received in GUILD_CREATE don't have the guild_id field
+        for mut channel in channels {
+            channel.guild_id = Some(guild.id.clone());
+            backend.upsert_channel(channel);
+        }
+
+        for member in members {
+            backend.upsert_member(&guild.id, member);
+        }
+
+        for presence in presences {
+            self.insert_presence(&guild.id, presence);
+        }
+
+        for voice_state in voice_states {
+            self.insert_voice_state(&guild.id, voice_state);
+        }
+
+        backend.upsert_guild(guild);
+    }
+
+    fn update_guild(&self, mut guild: Guild) {
+        let backend = self.backend();
+
+        // GUILD_UPDATE doesn't have the fields only sent within GUILD_CREATE
+        if let Some(old) = backend.guild(&guild.id) {
+            guild.joined_at = old.joined_at;
+            guild.large = old.large;
+            guild.unavailable = old.unavailable;
+            guild.member_count = old.member_count;
+        }
+        guild.channels = Vec::new();
+        guild.members = Vec::new();
+        guild.presences = Vec::new();
+        guild.voice_states = Vec::new();
+
+        backend.upsert_guild(guild);
+    }
+
+    fn remove_guild(&self, guild_id: &str) {
+        let backend = self.backend();
+
+        // The backend removes the cached messages of the channels with the guild
+        let mut message_index = self.message_index.write().unwrap();
+        for channel in backend.guild_channels(guild_id) {
+            message_index.remove(&channel.id);
+        }
+        drop(message_index);
+
+        backend.remove_guild(guild_id);
+    }
+
+    fn remove_channel(&self, channel_id: &str) {
+        let backend = self.backend();
+
+        // Remove the cached messages of the channel
+        if let Some(index) = self.message_index.write().unwrap().remove(channel_id) {
+            for (message_id, _) in index {
+                backend.remove_message(channel_id, &message_id);
+            }
+        }
+
+        backend.remove_channel(channel_id);
+    }
+
+    /// Save the presence, offline users are removed
+    fn insert_presence(&self, guild_id: &str, presence: Presence) {
+        if presence.status == "offline" {
+            self.backend().remove_presence(guild_id, &presence.user_id);
+        } else {
+            self.backend().upsert_presence(guild_id, presence);
+        }
+    }
+
+    /// Save the voice state, users that left the voice channel are removed
+    fn insert_voice_state(&self, guild_id: &str, mut voice_state: VoiceState) {
+        if voice_state.channel_id.is_none() {
+            self.backend().remove_voice_state(guild_id, &voice_state.user_id);
+        } else {
+            // Voice states received in GUILD_CREATE don't have the guild_id field
+            voice_state.guild_id = Some(guild_id.to_owned());
+            self.backend().upsert_voice_state(guild_id, voice_state);
+        }
+    }
+
+    /// Save the message in the message cache, removing the least recently used and
+    /// expired messages of the channel. It does nothing if the message cache is disabled.
+    pub(crate) fn insert_message(&self, message: Message) {
+        let limits = match *self.message_limits.read().unwrap() {
+            Some(limits) => limits,
+            None => return,
+        };
+
+        if limits.max_per_channel == 0 {
+            return;
+        }
+
+        let backend = self.backend();
+        let mut index = self.message_index.write().unwrap();
+        let channel = index.entry(message.channel_id.clone()).or_default();
+
+        channel.retain(|(id, inserted_at)| {
+            let is_expired = inserted_at.elapsed() >= limits.ttl;
+            if is_expired {
+                backend.remove_message(&message.channel_id, id);
+            }
+
+            id != &message.id && !is_expired
+        });
+
+        while channel.len() >= limits.max_per_channel {
+            if let Some((id, _)) = channel.pop_front() {
+                backend.remove_message(&message.channel_id, &id);
+            }
+        }
+
+        channel.push_back((message.id.clone(), Instant::now()));
+        backend.upsert_message(message);
+    }
+
+    fn remove_message(&self, channel_id: &str, message_id: &str) -> Option<Message> {
+        let ttl = self.message_limits.read().unwrap().as_ref()?.ttl;
+
+        let inserted_at = {
+            let mut index = self.message_index.write().unwrap();
+            let channel = index.get_mut(channel_id)?;
+            let position = channel.iter().position(|(id, _)| id == message_id)?;
+            channel.remove(position)?.1
+        };
+
+        let message = self.backend().remove_message(channel_id, message_id)?;
+
+        if inserted_at.elapsed() < ttl {
+            Some(message)
+        } else {
+            None
+        }
+    }
+}
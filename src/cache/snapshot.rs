@@ -0,0 +1,39 @@
+use crate::{error::Result, models::guild::Guild, runtime};
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A copy of the whole cache, it can be saved to a file and loaded later to warm up the cache
+/// after a restart. Each guild contains its channels, members, presences and voice states.
+///
+/// When it's created by the client at shutdown it also contains the session id and the last
+/// sequence of the gateway, so the client can RESUME the session instead of starting a new one.
+/// Cached messages are not included.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CacheSnapshot {
+    pub session_id: Option<String>,
+    pub sequence: Option<u64>,
+    pub guilds: Vec<Guild>,
+    pub unavailable_guild_ids: Vec<String>,
+}
+
+impl CacheSnapshot {
+    /// Load a snapshot from a JSON file asynchronously
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let bytes = runtime::read_file(path).await?;
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Save the snapshot as a JSON file asynchronously, first it's written to a temporary file and
+    /// then renamed, that way an old snapshot is never half overwritten
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+
+        runtime::write_file(&tmp, &serde_json::to_vec(self)?).await?;
+        runtime::rename(&tmp, path).await?;
+
+        Ok(())
+    }
+}
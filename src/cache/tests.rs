@@ -0,0 +1,612 @@
+//! Tests of the cache, the events are parsed from JSON payloads like the ones sent by Discord
+
+use super::{Cache, CacheBackend, CacheReady, CacheSnapshot, FileBackend, GuildStatus};
+use crate::models::gateway::{
+    events::{DispatchEvent, Event},
+    payload::{Opcode, Payload},
+};
+
+use serde_json::{json, Value};
+use std::{convert::TryFrom, env, fs, path::PathBuf, process, sync::Arc, thread, time::Duration};
+
+fn event(t: &str, d: Value) -> DispatchEvent {
+    let payload = Payload {
+        op: Opcode::Dispatch,
+        d: Some(d),
+        s: Some(1),
+        t: Some(t.to_string()),
+    };
+
+    match Event::try_from(payload) {
+        Ok(Event::Dispatch(event)) => event,
+        other => panic!("Invalid {} payload: {:?}", t, other),
+    }
+}
+
+fn update(cache: &Cache, t: &str, d: Value) {
+    cache.update(&mut event(t, d));
+}
+
+fn user(id: &str) -> Value {
+    json!({"id": id, "username": format!("user {}", id), "discriminator": "0001", "avatar": null})
+}
+
+fn member(user_id: &str, roles: &[&str]) -> Value {
+    json!({"user": user(user_id), "nick": null, "roles": roles, "joined_at": "2020-06-01T00:00:00+00:00",
+        "premium_since": null, "deaf": false, "mute": false})
+}
+
+fn role(id: &str, name: &str) -> Value {
+    json!({"id": id, "name": name, "color": 0, "hoist": false, "position": 1, "permissions": 0, "managed": false,
+        "mentionable": false})
+}
+
+fn channel(id: &str, guild_id: Option<&str>) -> Value {
+    json!({"id": id, "type": 0, "guild_id": guild_id, "name": format!("channel {}", id)})
+}
+
+fn presence(user_id: &str, status: &str) -> Value {
+    json!({"user": {"id": user_id}, "game": null, "status": status, "activities": []})
+}
+
+fn voice_state(user_id: &str, channel_id: Option<&str>) -> Value {
+    json!({"channel_id": channel_id, "user_id": user_id, "session_id": "abc", "deaf": false, "mute": false,
+        "self_deaf": false, "self_mute": false, "suppress": false})
+}
+
+/// A guild as sent within `GUILD_CREATE`, with a channel, two members, a presence and a voice state
+fn guild(id: &str) -> Value {
+    json!({
+        "id": id, "name": format!("guild {}", id), "icon": null, "splash": null, "owner_id": "1",
+        "region": "europe", "afk_channel_id": null, "afk_timeout": 300, "verification_level": 0,
+        "default_message_notifications": 0, "explicit_content_filter": 0, "roles": [role("20", "admin")],
+        "emojis": [], "mfa_level": 0, "premium_tier": 0, "preferred_locale": "en-US",
+        "joined_at": "2020-06-01T00:00:00+00:00", "large": false, "unavailable": false, "member_count": 2,
+        "channels": [channel("10", None)],
+        "members": [member("1", &["20"]), member("2", &[])],
+        "presences": [presence("1", "online")],
+        "voice_states": [voice_state("1", Some("10"))],
+    })
+}
+
+#[test]
+fn guild_create_caches_the_guild_and_its_entries() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    let cached = cache.guild("100").unwrap();
+    assert_eq!(cached.name, "guild 100");
+    assert!(cached.channels.is_empty() && cached.members.is_empty());
+
+    // Channels of GUILD_CREATE don't have guild_id
+    assert_eq!(cache.channel("10").unwrap().guild_id.as_deref(), Some("100"));
+    assert_eq!(cache.guild_members("100").len(), 2);
+    assert_eq!(cache.member("100", "1").unwrap().roles, vec!["20"]);
+    assert_eq!(cache.role("100", "20").unwrap().name, "admin");
+    assert_eq!(cache.presence("100", "1").unwrap().status, "online");
+    assert_eq!(cache.voice_channel_states("10").len(), 1);
+    assert_eq!(cache.guild_ids(), vec!["100"]);
+}
+
+#[test]
+fn guild_update_keeps_the_guild_create_fields() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    let mut updated = guild("100");
+    updated["name"] = json!("renamed");
+    for field in &[
+        "joined_at",
+        "large",
+        "member_count",
+        "channels",
+        "members",
+        "presences",
+        "voice_states",
+    ] {
+        updated.as_object_mut().unwrap().remove(*field);
+    }
+    update(&cache, "GUILD_UPDATE", updated);
+
+    let cached = cache.guild("100").unwrap();
+    assert_eq!(cached.name, "renamed");
+    assert_eq!(cached.member_count, Some(2));
+    assert_eq!(cached.joined_at, "2020-06-01T00:00:00+00:00");
+    assert_eq!(cache.guild_members("100").len(), 2);
+}
+
+#[test]
+fn guild_delete_marks_outages_and_removes_left_guilds() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    update(&cache, "GUILD_DELETE", json!({"id": "100", "unavailable": true}));
+    assert!(cache.guild("100").unwrap().unavailable);
+    assert_eq!(cache.guild_members("100").len(), 2);
+
+    update(&cache, "GUILD_DELETE", json!({"id": "100", "unavailable": false}));
+    assert!(cache.guild("100").is_none());
+    assert!(cache.channel("10").is_none());
+    assert!(cache.guild_members("100").is_empty());
+    assert!(cache.guild_presences("100").is_empty());
+    assert!(cache.guild_voice_states("100").is_empty());
+}
+
+#[test]
+fn ready_removes_the_guilds_the_user_is_not_in() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+    update(&cache, "GUILD_CREATE", guild("200"));
+
+    let ready = json!({"v": 6, "user": user("1"), "guilds": [{"id": "200", "unavailable": true}],
+        "session_id": "abc", "shard": null});
+    update(&cache, "READY", ready);
+
+    assert_eq!(cache.guild_ids(), vec!["200"]);
+}
+
+#[test]
+fn channel_events_update_the_channels() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    update(&cache, "CHANNEL_CREATE", channel("11", Some("100")));
+    assert_eq!(cache.guild_channels("100").len(), 2);
+
+    let mut renamed = channel("11", Some("100"));
+    renamed["name"] = json!("renamed");
+    update(&cache, "CHANNEL_UPDATE", renamed);
+    assert_eq!(cache.channel("11").unwrap().name.as_deref(), Some("renamed"));
+
+    update(&cache, "CHANNEL_DELETE", channel("11", Some("100")));
+    assert!(cache.channel("11").is_none());
+    assert_eq!(cache.guild_channels("100").len(), 1);
+}
+
+#[test]
+fn member_events_update_the_members_and_the_count() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    let mut added = member("3", &[]);
+    added["guild_id"] = json!("100");
+    update(&cache, "GUILD_MEMBER_ADD", added);
+    assert!(cache.member("100", "3").is_some());
+    assert_eq!(cache.guild("100").unwrap().member_count, Some(3));
+
+    let member_update = json!({"guild_id": "100", "roles": ["20"], "user": user("3"), "nick": "nick",
+        "premium_since": null});
+    update(&cache, "GUILD_MEMBER_UPDATE", member_update);
+    let cached = cache.member("100", "3").unwrap();
+    assert_eq!(cached.nick.as_deref(), Some("nick"));
+    assert_eq!(cached.roles, vec!["20"]);
+    // Fields that are not in the update are kept
+    assert_eq!(cached.joined_at.as_deref(), Some("2020-06-01T00:00:00+00:00"));
+
+    update(
+        &cache,
+        "GUILD_MEMBER_REMOVE",
+        json!({"guild_id": "100", "user": user("3")}),
+    );
+    assert!(cache.member("100", "3").is_none());
+    assert_eq!(cache.guild("100").unwrap().member_count, Some(2));
+}
+
+#[test]
+fn members_chunk_adds_members_and_presences() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    let chunk = json!({"guild_id": "100", "members": [member("3", &[]), member("4", &[])],
+        "chunk_index": 0, "chunk_count": 1, "presences": [presence("3", "idle")]});
+    update(&cache, "GUILD_MEMBERS_CHUNK", chunk);
+
+    assert_eq!(cache.guild_members("100").len(), 4);
+    assert_eq!(cache.presence("100", "3").unwrap().status, "idle");
+}
+
+#[test]
+fn role_events_update_the_roles() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    update(
+        &cache,
+        "GUILD_ROLE_CREATE",
+        json!({"guild_id": "100", "role": role("21", "mod")}),
+    );
+    assert_eq!(cache.roles("100").len(), 2);
+
+    update(
+        &cache,
+        "GUILD_ROLE_UPDATE",
+        json!({"guild_id": "100", "role": role("21", "moderator")}),
+    );
+    assert_eq!(cache.role("100", "21").unwrap().name, "moderator");
+
+    // Deleted roles are removed from the members too
+    update(&cache, "GUILD_ROLE_DELETE", json!({"guild_id": "100", "role_id": "20"}));
+    assert!(cache.role("100", "20").is_none());
+    assert!(cache.member("100", "1").unwrap().roles.is_empty());
+}
+
+#[test]
+fn offline_presences_are_removed() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    let mut online = presence("2", "dnd");
+    online["guild_id"] = json!("100");
+    online["roles"] = json!([]);
+    update(&cache, "PRESENCE_UPDATE", online.clone());
+    assert_eq!(cache.guild_presences("100").len(), 2);
+
+    online["status"] = json!("offline");
+    update(&cache, "PRESENCE_UPDATE", online);
+    assert!(cache.presence("100", "2").is_none());
+}
+
+#[test]
+fn voice_states_are_removed_when_leaving_the_channel() {
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    let mut joined = voice_state("2", Some("10"));
+    joined["guild_id"] = json!("100");
+    update(&cache, "VOICE_STATE_UPDATE", joined);
+    assert_eq!(cache.voice_channel_states("10").len(), 2);
+
+    let mut left = voice_state("1", None);
+    left["guild_id"] = json!("100");
+    update(&cache, "VOICE_STATE_UPDATE", left);
+    assert!(cache.voice_state("100", "1").is_none());
+    assert_eq!(cache.voice_channel_states("10").len(), 1);
+}
+
+fn message(id: &str, channel_id: &str, content: &str) -> Value {
+    json!({
+        "id": id, "channel_id": channel_id, "author": user("1"), "content": content,
+        "timestamp": "2020-06-01T00:00:00+00:00", "edited_timestamp": null, "tts": false,
+        "mention_everyone": false, "mentions": [], "mention_roles": [], "attachments": [],
+        "embed": [{"title": "embed"}], "pinned": false, "webhook_id": null, "type": 0,
+    })
+}
+
+fn message_cache(max_per_channel: usize, ttl: Duration) -> Cache {
+    let cache = Cache::new();
+    cache.set_message_limits(max_per_channel, ttl);
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    cache
+}
+
+#[test]
+fn message_cache_is_disabled_by_default() {
+    let cache = Cache::new();
+    update(&cache, "MESSAGE_CREATE", message("1", "10", "hi"));
+
+    assert!(cache.message("10", "1").is_none());
+}
+
+#[test]
+fn least_recently_used_messages_are_removed_first() {
+    let cache = message_cache(2, Duration::from_secs(60));
+    update(&cache, "MESSAGE_CREATE", message("1", "10", "first"));
+    update(&cache, "MESSAGE_CREATE", message("2", "10", "second"));
+
+    // The first message is used, so the second one is removed
+    assert!(cache.message("10", "1").is_some());
+    update(&cache, "MESSAGE_CREATE", message("3", "10", "third"));
+
+    assert!(cache.message("10", "2").is_none());
+    let ids: Vec<String> = cache.channel_messages("10").into_iter().map(|m| m.id).collect();
+    assert_eq!(ids, vec!["1", "3"]);
+}
+
+#[test]
+fn expired_messages_are_not_returned() {
+    let cache = message_cache(10, Duration::from_millis(50));
+    update(&cache, "MESSAGE_CREATE", message("1", "10", "hi"));
+    assert!(cache.message("10", "1").is_some());
+
+    thread::sleep(Duration::from_millis(60));
+
+    assert!(cache.message("10", "1").is_none());
+    assert!(cache.channel_messages("10").is_empty());
+
+    let mut delete = event("MESSAGE_DELETE", json!({"id": "1", "channel_id": "10"}));
+    cache.update(&mut delete);
+    match delete {
+        DispatchEvent::MessageDelete(e) => assert!(e.old_message.is_none()),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn message_update_keeps_the_fields_that_did_not_change() {
+    let cache = message_cache(10, Duration::from_secs(60));
+    update(&cache, "MESSAGE_CREATE", message("1", "10", "hi"));
+
+    let mut edit = event(
+        "MESSAGE_UPDATE",
+        json!({"id": "1", "channel_id": "10", "content": "edited", "edited_timestamp": "2020-06-02T00:00:00+00:00"}),
+    );
+    cache.update(&mut edit);
+
+    match edit {
+        DispatchEvent::MessageUpdate(e) => assert_eq!(e.old_message.unwrap().content, "hi"),
+        _ => unreachable!(),
+    }
+
+    let cached = cache.message("10", "1").unwrap();
+    assert_eq!(cached.content, "edited");
+    assert_eq!(cached.embed.len(), 1);
+
+    // An update with only the embeds, like the ones of links
+    update(
+        &cache,
+        "MESSAGE_UPDATE",
+        json!({"id": "1", "channel_id": "10", "embeds": []}),
+    );
+
+    let cached = cache.message("10", "1").unwrap();
+    assert_eq!(cached.content, "edited");
+    assert!(cached.embed.is_empty());
+}
+
+#[test]
+fn deleted_messages_are_sent_within_the_event() {
+    let cache = message_cache(10, Duration::from_secs(60));
+    update(&cache, "MESSAGE_CREATE", message("1", "10", "one"));
+    update(&cache, "MESSAGE_CREATE", message("2", "10", "two"));
+
+    let mut bulk = event(
+        "MESSAGE_DELETE_BULK",
+        json!({"ids": ["1", "2", "3"], "channel_id": "10"}),
+    );
+    cache.update(&mut bulk);
+
+    match bulk {
+        DispatchEvent::MessageDeleteBulk(e) => assert_eq!(e.old_messages.len(), 2),
+        _ => unreachable!(),
+    }
+    assert!(cache.channel_messages("10").is_empty());
+}
+
+#[test]
+fn deleted_channels_remove_their_messages() {
+    let cache = message_cache(10, Duration::from_secs(60));
+    update(&cache, "MESSAGE_CREATE", message("1", "10", "hi"));
+
+    update(&cache, "CHANNEL_DELETE", channel("10", Some("100")));
+
+    assert!(cache.message("10", "1").is_none());
+}
+
+/// A directory that is removed at the end of the test
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("panda-cache-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&path);
+
+        TempDir(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn file_cache(dir: &TempDir) -> (Cache, Arc<FileBackend>) {
+    let backend = Arc::new(FileBackend::new(&dir.0).unwrap());
+    let cache = Cache::new();
+    cache.set_backend(backend.clone());
+
+    (cache, backend)
+}
+
+#[test]
+fn file_backend_sees_the_changes_before_they_are_saved() {
+    let dir = TempDir::new("pending");
+    let (cache, backend) = file_cache(&dir);
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    assert_eq!(cache.guild_ids(), vec!["100"]);
+    assert_eq!(cache.guild_members("100").len(), 2);
+    assert_eq!(cache.channel("10").unwrap().guild_id.as_deref(), Some("100"));
+
+    update(
+        &cache,
+        "GUILD_MEMBER_REMOVE",
+        json!({"guild_id": "100", "user": user("2")}),
+    );
+    assert_eq!(cache.guild_members("100").len(), 1);
+
+    backend.flush();
+
+    let members = dir.0.join("guilds").join("100").join("members");
+    assert!(members.join("1.json").exists());
+    assert!(!members.join("2.json").exists());
+    assert_eq!(cache.guild_members("100").len(), 1);
+}
+
+#[test]
+fn file_backend_lookups_dont_read_the_disk() {
+    let dir = TempDir::new("lookups");
+    let (cache, backend) = file_cache(&dir);
+    update(&cache, "GUILD_CREATE", guild("100"));
+    backend.flush();
+
+    fs::remove_dir_all(dir.0.join("guilds").join("100").join("members")).unwrap();
+
+    assert_eq!(cache.guild_members("100").len(), 2);
+    assert!(cache.member("100", "1").is_some());
+}
+
+#[test]
+fn file_backend_removes_the_guild_directory() {
+    let dir = TempDir::new("remove-guild");
+    let (cache, backend) = file_cache(&dir);
+    cache.set_message_limits(10, Duration::from_secs(60));
+    update(&cache, "GUILD_CREATE", guild("100"));
+    update(&cache, "MESSAGE_CREATE", message("1", "10", "hi"));
+    backend.flush();
+
+    update(&cache, "GUILD_DELETE", json!({"id": "100", "unavailable": false}));
+    assert!(cache.guild_ids().is_empty());
+    assert!(cache.channel("10").is_none());
+
+    backend.flush();
+
+    assert!(!dir.0.join("guilds").join("100").exists());
+    assert!(!dir.0.join("messages").join("10").exists());
+}
+
+#[test]
+fn file_backend_keeps_the_data_but_not_the_messages() {
+    let dir = TempDir::new("reopen");
+    {
+        let (cache, _) = file_cache(&dir);
+        cache.set_message_limits(10, Duration::from_secs(60));
+        update(&cache, "GUILD_CREATE", guild("100"));
+        update(&cache, "MESSAGE_CREATE", message("1", "10", "hi"));
+    }
+
+    let backend = FileBackend::new(&dir.0).unwrap();
+
+    assert_eq!(backend.guild_ids(), vec!["100"]);
+    assert_eq!(backend.guild_members("100").len(), 2);
+    assert_eq!(backend.channel("10").unwrap().guild_id.as_deref(), Some("100"));
+    assert_eq!(backend.presence("100", "1").unwrap().status, "online");
+    assert!(backend.message("10", "1").is_none());
+}
+
+#[test]
+fn deleted_roles_are_not_rewritten_in_every_member() {
+    let dir = TempDir::new("role-delete");
+    let (cache, backend) = file_cache(&dir);
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    update(&cache, "GUILD_ROLE_DELETE", json!({"guild_id": "100", "role_id": "20"}));
+
+    assert_eq!(backend.member("100", "1").unwrap().roles, vec!["20"]);
+    assert!(cache.member("100", "1").unwrap().roles.is_empty());
+    assert!(cache.guild_members("100").iter().all(|m| m.roles.is_empty()));
+}
+
+fn ready(guild_ids: &[&str]) -> Value {
+    let guilds: Vec<Value> = guild_ids
+        .iter()
+        .map(|id| json!({"id": id, "unavailable": true}))
+        .collect();
+
+    json!({"v": 6, "user": user("1"), "guilds": guilds, "session_id": "abc", "shard": null})
+}
+
+/// Track and update like the client does, returns the guild status and if the cache became ready
+fn dispatch(cache: &Cache, t: &str, d: Value) -> (Option<GuildStatus>, Option<CacheReady>) {
+    let mut event = event(t, d);
+    let status = cache.track_guild(&event);
+    cache.update(&mut event);
+
+    (status, cache.take_ready())
+}
+
+#[test]
+fn leaving_a_guild_removes_it() {
+    let cache = Cache::new();
+    dispatch(&cache, "GUILD_CREATE", guild("100"));
+
+    // Discord doesn't send `unavailable` when the user leaves or is kicked
+    let (status, _) = dispatch(&cache, "GUILD_DELETE", json!({"id": "100"}));
+
+    assert_eq!(status, Some(GuildStatus::Left));
+    assert!(cache.guild("100").is_none());
+}
+
+#[test]
+fn tracker_is_ready_once_all_guilds_are_received() {
+    let cache = Cache::new();
+
+    let (_, ready_event) = dispatch(&cache, "READY", ready(&["100", "200"]));
+    assert!(ready_event.is_none() && !cache.is_ready());
+    assert!(cache.is_guild_unavailable("100"));
+
+    let (status, ready_event) = dispatch(&cache, "GUILD_CREATE", guild("100"));
+    assert_eq!(status, Some(GuildStatus::Available));
+    assert!(ready_event.is_none());
+
+    // The other guild has an outage
+    let (status, ready_event) = dispatch(&cache, "GUILD_DELETE", json!({"id": "200", "unavailable": true}));
+    assert_eq!(status, Some(GuildStatus::Unavailable));
+
+    let ready_event = ready_event.unwrap();
+    assert_eq!(ready_event.guild_ids, vec!["100"]);
+    assert_eq!(ready_event.unavailable_guild_ids, vec!["200"]);
+    assert!(cache.is_ready());
+
+    // It's sent only once
+    let (status, ready_event) = dispatch(&cache, "GUILD_CREATE", guild("200"));
+    assert_eq!(status, Some(GuildStatus::Available));
+    assert!(ready_event.is_none());
+    assert!(cache.unavailable_guild_ids().is_empty());
+
+    let (status, _) = dispatch(&cache, "GUILD_CREATE", guild("300"));
+    assert_eq!(status, Some(GuildStatus::Joined));
+}
+
+#[test]
+fn tracker_is_ready_after_a_restore() {
+    let cache = Cache::new();
+    let snapshot = CacheSnapshot {
+        session_id: Some("abc".into()),
+        sequence: Some(10),
+        guilds: vec![serde_json::from_value(guild("100")).unwrap()],
+        unavailable_guild_ids: vec!["200".into()],
+    };
+
+    cache.import(snapshot);
+    assert!(cache.is_ready());
+    assert!(cache.is_guild_unavailable("200"));
+
+    // The resumed session doesn't receive READY
+    let (_, ready_event) = dispatch(&cache, "RESUMED", json!({}));
+    let ready_event = ready_event.unwrap();
+    assert_eq!(ready_event.guild_ids, vec!["100"]);
+    assert_eq!(ready_event.unavailable_guild_ids, vec!["200"]);
+
+    let (_, ready_event) = dispatch(&cache, "GUILD_CREATE", guild("200"));
+    assert!(ready_event.is_none());
+}
+
+#[tokio::test]
+async fn snapshot_round_trip() {
+    let dir = TempDir::new("snapshot");
+    let cache = Cache::new();
+    update(&cache, "GUILD_CREATE", guild("100"));
+
+    let mut snapshot = cache.export();
+    snapshot.session_id = Some("abc".into());
+    snapshot.sequence = Some(10);
+
+    fs::create_dir_all(&dir.0).unwrap();
+    let path = dir.0.join("snapshot.json");
+    snapshot.save(&path).await.unwrap();
+    let snapshot = CacheSnapshot::load(&path).await.unwrap();
+    assert_eq!(snapshot.session_id.as_deref(), Some("abc"));
+    assert_eq!(snapshot.sequence, Some(10));
+
+    let cache = Cache::new();
+    cache.import(snapshot);
+
+    assert_eq!(cache.guild("100").unwrap().name, "guild 100");
+    assert_eq!(cache.channel("10").unwrap().guild_id.as_deref(), Some("100"));
+    assert_eq!(cache.member("100", "1").unwrap().roles, vec!["20"]);
+    assert!(cache.member("100", "2").is_some());
+    assert_eq!(cache.presence("100", "1").unwrap().user_id, "1");
+    assert_eq!(cache.voice_state("100", "1").unwrap().channel_id.as_deref(), Some("10"));
+}
@@ -0,0 +1,111 @@
+use crate::models::gateway::events::DispatchEvent;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The change of availability of a guild, detected from `READY`, `GUILD_CREATE`
+/// and `GUILD_DELETE` events
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum GuildStatus {
+    /// The guild was received after `READY` or it recovered from an outage
+    Available,
+    /// The user joined a new guild
+    Joined,
+    /// The guild became unavailable because of an outage
+    Unavailable,
+    /// The user left the guild, or was kicked or banned
+    Left,
+}
+
+/// Event sent when all guilds of the [`Ready`] event have been received and saved in the
+/// cache, guilds that are still unavailable because of an outage are listed separately.
+///
+/// [`Ready`]: ../models/gateway/events/struct.Ready.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CacheReady {
+    pub guild_ids: Vec<String>,
+    pub unavailable_guild_ids: Vec<String>,
+}
+
+/// Keeps the ids of unavailable guilds, and the guilds of the `READY` event that
+/// have not been received yet
+#[derive(Default)]
+pub(crate) struct GuildTracker {
+    unavailable: HashSet<String>,
+    // Guilds of READY that have not been received
+    pending: HashSet<String>,
+    // True between READY and the reception of all its guilds
+    is_waiting: bool,
+    is_ready: bool,
+}
+
+impl GuildTracker {
+    pub(crate) fn update(&mut self, event: &DispatchEvent) -> Option<GuildStatus> {
+        match event {
+            DispatchEvent::Ready(e) => {
+                let ids: HashSet<String> = e.guilds.iter().map(|g| g.id.clone()).collect();
+                self.unavailable = ids.clone();
+                self.pending = ids;
+                self.is_waiting = true;
+                self.is_ready = false;
+
+                None
+            }
+            DispatchEvent::GuildCreate(e) => {
+                self.pending.remove(&e.id);
+
+                if self.unavailable.remove(&e.id) {
+                    Some(GuildStatus::Available)
+                } else {
+                    Some(GuildStatus::Joined)
+                }
+            }
+            DispatchEvent::GuildDelete(e) => {
+                // In both cases the guild of READY will not be received
+                self.pending.remove(&e.id);
+
+                if e.unavailable {
+                    self.unavailable.insert(e.id.clone());
+                    Some(GuildStatus::Unavailable)
+                } else {
+                    self.unavailable.remove(&e.id);
+                    Some(GuildStatus::Left)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Restore the state of a snapshot, the guilds are already cached so it's ready. A resumed
+    /// session doesn't receive `READY`, so `take_ready` returns true with the next event.
+    pub(crate) fn restore(&mut self, unavailable: Vec<String>) {
+        self.unavailable = unavailable.into_iter().collect();
+        self.pending.clear();
+        self.is_waiting = true;
+        self.is_ready = true;
+    }
+
+    /// Returns true only once, when all guilds of the `READY` event were received
+    pub(crate) fn take_ready(&mut self) -> bool {
+        if self.is_waiting && self.pending.is_empty() {
+            self.is_waiting = false;
+            self.is_ready = true;
+
+            return true;
+        }
+
+        false
+    }
+
+    pub(crate) fn is_ready(&self) -> bool {
+        self.is_ready
+    }
+
+    pub(crate) fn is_unavailable(&self, guild_id: &str) -> bool {
+        self.unavailable.contains(guild_id)
+    }
+
+    pub(crate) fn unavailable_ids(&self) -> Vec<String> {
+        self.unavailable.iter().cloned().collect()
+    }
+}
@@ -0,0 +1,62 @@
+//! CancellationToken
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use futures::{
+    channel::oneshot,
+    future::{FutureExt, Shared},
+};
+
+/// A token that is cancelled when the client is shutting down. It can be cloned and
+/// moved to other tasks, all the clones share the same state.
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    is_cancelled: AtomicBool,
+    sender: Mutex<Option<oneshot::Sender<()>>>,
+    receiver: Shared<oneshot::Receiver<()>>,
+}
+
+impl CancellationToken {
+    pub(crate) fn new() -> Self {
+        let (sender, receiver) = oneshot::channel();
+
+        CancellationToken {
+            inner: Arc::new(Inner {
+                is_cancelled: AtomicBool::new(false),
+                sender: Mutex::new(Some(sender)),
+                receiver: receiver.shared(),
+            }),
+        }
+    }
+
+    /// Returns true if the token was already cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns a future that completes when the token is cancelled
+    pub fn cancelled(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.inner.receiver.clone().map(|_| ())
+    }
+
+    /// Cancel the token, waking up all tasks waiting on [`cancelled`]
+    ///
+    /// [`cancelled`]: #method.cancelled
+    pub(crate) fn cancel(&self) {
+        self.inner.is_cancelled.store(true, Ordering::Relaxed);
+
+        if let Some(sender) = self.inner.sender.lock().unwrap().take() {
+            let _ = sender.send(());
+        }
+    }
+}
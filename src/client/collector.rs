@@ -0,0 +1,223 @@
+//! Collectors used by [`SessionData::wait_for`] and [`SessionData::collect`]
+//!
+//! [`SessionData::wait_for`]: ../struct.SessionData.html#method.wait_for
+//! [`SessionData::collect`]: ../struct.SessionData.html#method.collect
+
+use crate::runtime;
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+    time::Duration,
+};
+
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    lock::Mutex,
+    select,
+    stream::{self, BoxStream, StreamExt},
+    FutureExt,
+};
+
+type Predicate<E> = Box<dyn Fn(&E) -> bool + Send + Sync>;
+
+/// A single listener waiting for events of type `E`
+struct Collector<E> {
+    predicate: Predicate<E>,
+    sender: UnboundedSender<E>,
+    // None means that there is no limit
+    remaining: Option<usize>,
+}
+
+type CollectorMap = HashMap<TypeId, Vec<Box<dyn Any + Send + Sync>>>;
+
+/// This struct it's where all active collectors are saved, grouped by event type
+#[derive(Default)]
+pub(crate) struct Collectors {
+    // It's shared with the streams of with_timeout, to remove their collector when they end
+    collectors: Arc<Mutex<CollectorMap>>,
+}
+
+impl Collectors {
+    /// Register a new collector, and returns the receiver of the matched events. With a `max`
+    /// of 0 nothing is registered and the receiver is already closed.
+    pub(crate) async fn register<E, F>(&self, predicate: F, max: Option<usize>) -> UnboundedReceiver<E>
+    where
+        E: Clone + Send + Sync + 'static,
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+
+        if max == Some(0) {
+            return receiver;
+        }
+
+        let collector = Collector {
+            predicate: Box::new(predicate),
+            sender,
+            remaining: max,
+        };
+
+        let mut collectors = self.collectors.lock().await;
+
+        // Remove the collectors of this type that were dropped before receiving an event
+        prune::<E>(&mut collectors);
+
+        collectors
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(Box::new(collector));
+
+        receiver
+    }
+
+    /// Returns a stream that yields the received events until the receiver is closed
+    /// or the timeout is reached. When the timeout is reached the collector is removed.
+    pub(crate) fn with_timeout<E>(&self, receiver: UnboundedReceiver<E>, timeout: Duration) -> BoxStream<'static, E>
+    where
+        E: Clone + Send + Sync + 'static,
+    {
+        let collectors = self.collectors.clone();
+        let delay = runtime::sleep(timeout);
+
+        stream::unfold(
+            (receiver.fuse(), delay.fuse(), collectors),
+            |(mut receiver, mut delay, collectors)| async move {
+                select! {
+                    event = receiver.next() => event.map(|e| (e, (receiver, delay, collectors))),
+                    _ = delay => {
+                        // Close the channel first, that way the collector is seen as closed
+                        drop(receiver);
+                        prune::<E>(&mut *collectors.lock().await);
+
+                        None
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+
+    /// Send the event to all collectors of its type whose predicate matches, and
+    /// removes the collectors that are finished or were dropped
+    pub(crate) async fn dispatch<E>(&self, event: &E)
+    where
+        E: Clone + Send + Sync + 'static,
+    {
+        let mut collectors = self.collectors.lock().await;
+
+        let list = match collectors.get_mut(&TypeId::of::<E>()) {
+            Some(list) => list,
+            None => return,
+        };
+
+        list.retain_mut(|c| {
+            // It's safe to unwrap, the list is indexed by the collector's event type
+            let c = c.downcast_mut::<Collector<E>>().unwrap();
+
+            if c.sender.is_closed() {
+                return false;
+            }
+
+            if !(c.predicate)(event) {
+                return true;
+            }
+
+            if c.sender.unbounded_send(event.clone()).is_err() {
+                return false;
+            }
+
+            // Update the limit, and remove the collector when it reaches zero
+            match c.remaining.as_mut() {
+                Some(remaining) => {
+                    *remaining = remaining.saturating_sub(1);
+                    *remaining > 0
+                }
+                None => true,
+            }
+        });
+
+        if list.is_empty() {
+            collectors.remove(&TypeId::of::<E>());
+        }
+    }
+}
+
+/// Remove the collectors of type `E` that were dropped
+fn prune<E: 'static>(collectors: &mut CollectorMap) {
+    let type_id = TypeId::of::<E>();
+
+    if let Some(list) = collectors.get_mut(&type_id) {
+        // It's safe to unwrap, the list is indexed by the collector's event type
+        list.retain(|c| !c.downcast_ref::<Collector<E>>().unwrap().sender.is_closed());
+
+        if list.is_empty() {
+            collectors.remove(&type_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Event(u32);
+
+    async fn count<E: 'static>(collectors: &Collectors) -> usize {
+        let collectors = collectors.collectors.lock().await;
+
+        collectors.get(&TypeId::of::<E>()).map_or(0, Vec::len)
+    }
+
+    #[tokio::test]
+    async fn collector_is_removed_after_max_events() {
+        let collectors = Collectors::default();
+        let mut receiver = collectors.register(|e: &Event| e.0 % 2 == 0, Some(2)).await;
+
+        for i in 0..5 {
+            collectors.dispatch(&Event(i)).await;
+        }
+
+        assert_eq!(receiver.next().await, Some(Event(0)));
+        assert_eq!(receiver.next().await, Some(Event(2)));
+        assert_eq!(receiver.next().await, None);
+        assert_eq!(count::<Event>(&collectors).await, 0);
+    }
+
+    #[tokio::test]
+    async fn zero_max_does_not_underflow() {
+        let collectors = Collectors::default();
+        let mut receiver = collectors.register(|_: &Event| true, Some(0)).await;
+
+        collectors.dispatch(&Event(1)).await;
+
+        assert_eq!(receiver.next().await, None);
+        assert_eq!(count::<Event>(&collectors).await, 0);
+    }
+
+    #[tokio::test]
+    async fn collector_is_removed_on_timeout() {
+        let collectors = Collectors::default();
+        let receiver = collectors.register(|_: &Event| true, None).await;
+
+        let events: Vec<Event> = collectors
+            .with_timeout(receiver, Duration::from_millis(50))
+            .collect()
+            .await;
+
+        assert!(events.is_empty());
+        assert_eq!(count::<Event>(&collectors).await, 0);
+    }
+
+    #[tokio::test]
+    async fn dropped_collectors_are_removed_on_register() {
+        let collectors = Collectors::default();
+        drop(collectors.register(|_: &Event| true, None).await);
+
+        let _receiver = collectors.register(|_: &Event| true, None).await;
+
+        assert_eq!(count::<Event>(&collectors).await, 1);
+    }
+}
@@ -1,9 +1,19 @@
+use crate::{cache::CacheBackend, models::channel::AllowedMentions};
+
+use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
+
 /// Config contains all customizable options of the Client
 pub struct Config {
     pub(crate) gateway_large_treshold: u8,
     pub(crate) gateway_guilds_subscriptions: bool,
     pub(crate) gateway_shard_id: u64,
     pub(crate) gateway_num_shards: u64,
+    pub(crate) handler_timeout: Option<Duration>,
+    pub(crate) event_timeouts: HashMap<String, Duration>,
+    pub(crate) message_cache: Option<(usize, Duration)>,
+    pub(crate) cache_backend: Option<Arc<dyn CacheBackend>>,
+    pub(crate) cache_snapshot: Option<PathBuf>,
+    pub(crate) allowed_mentions: Option<AllowedMentions>,
 }
 
 impl Config {
@@ -17,8 +27,20 @@ impl Config {
             gateway_guilds_subscriptions: true,
             gateway_shard_id: 0,
             gateway_num_shards: 1,
+            handler_timeout: None,
+            event_timeouts: HashMap::new(),
+            message_cache: None,
+            cache_backend: None,
+            cache_snapshot: None,
+            allowed_mentions: None,
         }
     }
+
+    /// Returns the timeout of the handler of the given event, the per-event timeout
+    /// has priority over the global one
+    pub(crate) fn handler_timeout(&self, event_name: &str) -> Option<Duration> {
+        self.event_timeouts.get(event_name).copied().or(self.handler_timeout)
+    }
 }
 
 pub struct ConfigBuilder {
@@ -26,6 +48,12 @@ pub struct ConfigBuilder {
     pub(crate) gateway_guilds_subscriptions: bool,
     pub(crate) gateway_shard_id: u64,
     pub(crate) gateway_num_shards: u64,
+    pub(crate) handler_timeout: Option<Duration>,
+    pub(crate) event_timeouts: HashMap<String, Duration>,
+    pub(crate) message_cache: Option<(usize, Duration)>,
+    pub(crate) cache_backend: Option<Arc<dyn CacheBackend>>,
+    pub(crate) cache_snapshot: Option<PathBuf>,
+    pub(crate) allowed_mentions: Option<AllowedMentions>,
 }
 
 impl ConfigBuilder {
@@ -35,6 +63,12 @@ impl ConfigBuilder {
             gateway_guilds_subscriptions: true,
             gateway_shard_id: 0,
             gateway_num_shards: 1,
+            handler_timeout: None,
+            event_timeouts: HashMap::new(),
+            message_cache: None,
+            cache_backend: None,
+            cache_snapshot: None,
+            allowed_mentions: None,
         }
     }
 
@@ -62,6 +96,68 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set the maximum time that a handler can run, when it's exceeded the handler is dropped
+    /// and a timeout error is reported. Default none.
+    pub fn set_handler_timeout(mut self, timeout: Duration) -> Self {
+        self.handler_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Set the maximum time that the handler of the given event can run, it has priority over
+    /// [`set_handler_timeout`]. The event name is the Discord name, like `MESSAGE_CREATE`.
+    ///
+    /// The handlers that aren't Discord events use these names: `GUILD_AVAILABLE`, `GUILD_JOIN`,
+    /// `GUILD_UNAVAILABLE`, `GUILD_LEAVE` and `CACHE_READY`.
+    ///
+    /// [`set_handler_timeout`]: #method.set_handler_timeout
+    pub fn set_event_timeout(mut self, event_name: impl Into<String>, timeout: Duration) -> Self {
+        self.event_timeouts.insert(event_name.into(), timeout);
+
+        self
+    }
+
+    /// Enable the message cache, it saves up to `max_per_channel` messages of every channel, each
+    /// one for `ttl` time. The cached messages are sent within [`MessageUpdate`] and
+    /// [`MessageDelete`] events. Default disabled.
+    ///
+    /// [`MessageUpdate`]: ../../models/gateway/events/struct.MessageUpdate.html
+    /// [`MessageDelete`]: ../../models/gateway/events/struct.MessageDelete.html
+    pub fn set_message_cache(mut self, max_per_channel: usize, ttl: Duration) -> Self {
+        self.message_cache = Some((max_per_channel, ttl));
+
+        self
+    }
+
+    /// Set the backend where the cache is saved, like [`FileBackend`] or a custom one.
+    /// Default [`MemoryBackend`].
+    ///
+    /// [`FileBackend`]: ../../cache/struct.FileBackend.html
+    /// [`MemoryBackend`]: ../../cache/struct.MemoryBackend.html
+    pub fn set_cache_backend(mut self, backend: impl CacheBackend + 'static) -> Self {
+        self.cache_backend = Some(Arc::new(backend));
+
+        self
+    }
+
+    /// Set the file of the cache snapshot. If the file exists, the cache is loaded from it when the
+    /// client starts and the saved session is resumed, so handlers have a complete cache right
+    /// away. The snapshot is saved to the file when the client shuts down. Default none.
+    pub fn set_cache_snapshot(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_snapshot = Some(path.into());
+
+        self
+    }
+
+    /// Set the default allowed mentions of the messages sent by the client, like
+    /// `AllowedMentions::new().parse(MentionKind::Users)` to disable `@everyone` and role pings.
+    /// Messages with their own allowed mentions override it. Default all mentions are allowed.
+    pub fn set_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+
+        self
+    }
+
     /// Build a Config struct
     pub fn build(self) -> Config {
         Config {
@@ -69,6 +165,12 @@ impl ConfigBuilder {
             gateway_guilds_subscriptions: self.gateway_guilds_subscriptions,
             gateway_shard_id: self.gateway_shard_id,
             gateway_num_shards: self.gateway_num_shards,
+            handler_timeout: self.handler_timeout,
+            event_timeouts: self.event_timeouts,
+            message_cache: self.message_cache,
+            cache_backend: self.cache_backend,
+            cache_snapshot: self.cache_snapshot,
+            allowed_mentions: self.allowed_mentions,
         }
     }
 }
@@ -1,6 +1,6 @@
 // models
 use super::session::SessionData;
-use crate::models::gateway::events::*;
+use crate::{cache::CacheReady, models::gateway::events::*};
 
 use std::{error::Error, sync::Arc};
 
@@ -17,8 +17,12 @@ macro_rules! event_trait {
     };
 }
 
+// Catch-all function trait
+type EventFn<S> = event_trait!(DispatchEvent);
+
 // READY function trait
 type ReadyFn<S> = event_trait!(Ready);
+type ResumedFn<S> = event_trait!(Resumed);
 
 // CHANNEL functions trait
 type ChannelCreateFn<S> = event_trait!(ChannelCreate);
@@ -41,6 +45,17 @@ type GuildMembersChunkFn<S> = event_trait!(GuildMembersChunk);
 type GuildRoleCreateFn<S> = event_trait!(GuildRoleCreate);
 type GuildRoleUpdateFn<S> = event_trait!(GuildRoleUpdate);
 type GuildRoleDeleteFn<S> = event_trait!(GuildRoleDelete);
+type GuildAvailableFn<S> = event_trait!(GuildCreate);
+type GuildJoinFn<S> = event_trait!(GuildCreate);
+type GuildUnavailableFn<S> = event_trait!(GuildDelete);
+type GuildLeaveFn<S> = event_trait!(GuildDelete);
+
+// CACHE function trait
+type CacheReadyFn<S> = event_trait!(CacheReady);
+
+// INVITE functions trait
+type InviteCreateFn<S> = event_trait!(InviteCreate);
+type InviteDeleteFn<S> = event_trait!(InviteDelete);
 
 // MESSAGE functions trait
 type MessageCreateFn<S> = event_trait!(MessageCreate);
@@ -57,11 +72,20 @@ type PresenceUpdateFn<S> = event_trait!(PresenceUpdate);
 type TypingStartFn<S> = event_trait!(TypingStart);
 type UserUpdateFn<S> = event_trait!(UserUpdate);
 
+// Voice functions trait
+type VoiceStateUpdateFn<S> = event_trait!(VoiceStateUpdate);
+type VoiceServerUpdateFn<S> = event_trait!(VoiceServerUpdate);
+
+// Webhooks functions trait
+type WebhooksUpdateFn<S> = event_trait!(WebhooksUpdate);
+
 type OptionBox<T> = Option<Box<T>>;
 
 /// This struct it's where all functions created by the user will be saved
 pub(crate) struct EventHandler<S> {
+    pub(crate) event: OptionBox<EventFn<S>>,
     pub(crate) ready: OptionBox<ReadyFn<S>>,
+    pub(crate) resumed: OptionBox<ResumedFn<S>>,
 
     // Channel
     pub(crate) channel_create: OptionBox<ChannelCreateFn<S>>,
@@ -84,6 +108,17 @@ pub(crate) struct EventHandler<S> {
     pub(crate) guild_role_create: OptionBox<GuildRoleCreateFn<S>>,
     pub(crate) guild_role_update: OptionBox<GuildRoleUpdateFn<S>>,
     pub(crate) guild_role_delete: OptionBox<GuildRoleDeleteFn<S>>,
+    pub(crate) guild_available: OptionBox<GuildAvailableFn<S>>,
+    pub(crate) guild_join: OptionBox<GuildJoinFn<S>>,
+    pub(crate) guild_unavailable: OptionBox<GuildUnavailableFn<S>>,
+    pub(crate) guild_leave: OptionBox<GuildLeaveFn<S>>,
+
+    // Cache
+    pub(crate) cache_ready: OptionBox<CacheReadyFn<S>>,
+
+    // Invite
+    pub(crate) invite_create: OptionBox<InviteCreateFn<S>>,
+    pub(crate) invite_delete: OptionBox<InviteDeleteFn<S>>,
 
     // Message
     pub(crate) message_create: OptionBox<MessageCreateFn<S>>,
@@ -99,12 +134,21 @@ pub(crate) struct EventHandler<S> {
     pub(crate) presence_update: OptionBox<PresenceUpdateFn<S>>,
     pub(crate) typing_start: OptionBox<TypingStartFn<S>>,
     pub(crate) user_update: OptionBox<UserUpdateFn<S>>,
+
+    // Voice
+    pub(crate) voice_state_update: OptionBox<VoiceStateUpdateFn<S>>,
+    pub(crate) voice_server_update: OptionBox<VoiceServerUpdateFn<S>>,
+
+    // Webhooks
+    pub(crate) webhooks_update: OptionBox<WebhooksUpdateFn<S>>,
 }
 
 impl<S> EventHandler<S> {
     pub(crate) fn new() -> Self {
         Self {
+            event: None,
             ready: None,
+            resumed: None,
 
             // Channel
             channel_create: None,
@@ -127,6 +171,17 @@ impl<S> EventHandler<S> {
             guild_role_create: None,
             guild_role_update: None,
             guild_role_delete: None,
+            guild_available: None,
+            guild_join: None,
+            guild_unavailable: None,
+            guild_leave: None,
+
+            // Cache
+            cache_ready: None,
+
+            // Invite
+            invite_create: None,
+            invite_delete: None,
 
             // Message
             message_create: None,
@@ -141,6 +196,13 @@ impl<S> EventHandler<S> {
             presence_update: None,
             typing_start: None,
             user_update: None,
+
+            // Voice
+            voice_state_update: None,
+            voice_server_update: None,
+
+            // Webhooks
+            webhooks_update: None,
         }
     }
 }
@@ -1,15 +1,19 @@
 //! # Panda Client
 
 //modules
+mod cancellation;
+mod collector;
 pub mod config;
 mod handler;
 mod session;
 
+pub use cancellation::CancellationToken;
 pub use config::Config;
 pub use session::SessionData;
-use handler::EventHandler;
+use handler::{EventHandler, EventResult};
 
 use crate::{
+    cache::{CacheReady, CacheSnapshot, GuildStatus},
     error::{PandaError, Result},
     runtime,
     gateway::{heartbeat, GatewayConnection},
@@ -20,26 +24,56 @@ use crate::{
     },
 };
 
-use futures::{sink::SinkExt, stream::StreamExt, FutureExt};
-use std::{future::Future, sync::Arc};
+use futures::{future::BoxFuture, select, sink::SinkExt, stream::StreamExt, FutureExt};
+use std::{future::Future, io, sync::Arc, time::Duration};
 
 /// This macro it's used to handle all dispatched events of handler::EventHandler
 macro_rules! handle_event {
-    ($client: ident, $kind: ident, $event: expr) => {
+    ($client: ident, $name: expr, $kind: ident, $event: expr) => {
+        let event = $event;
+
+        // Feed the collectors created with wait_for and collect
+        $client.session.collectors.dispatch(&event).await;
+
+        call_handler!($client, $name, $kind, event);
+    };
+}
+
+/// This macro it's used to call a handler without feeding the collectors, for handlers
+/// that receive the same event of other handler. The event is only evaluated if there is a handler.
+/// `$name` is the name used to find the handler timeout, see `ConfigBuilder::set_event_timeout`
+macro_rules! call_handler {
+    ($client: ident, $name: expr, $kind: ident, $event: expr) => {
         if let Some(func) = &($client).handler.$kind {
             let session = $client.session.clone();
-            let future = func(session, $event);
-            // CRATE ASDJASLDJAS
-            crate::runtime::spawn(async move {
-                if let Err(e) = future.await {
-                    // TODO: Add display and event name
-                    log::error!("Handler error: {:?}", e);
-                };
-            });
+            let timeout = $client.config.handler_timeout($name);
+            spawn_handler($name, func(session, $event), timeout);
         }
     };
 }
 
+/// Spawn the handler future, if it exceeds the timeout it will be dropped
+fn spawn_handler(name: &'static str, future: BoxFuture<'static, EventResult>, timeout: Option<Duration>) {
+    runtime::spawn(async move {
+        let result = match timeout {
+            Some(timeout) => {
+                let mut future = future.fuse();
+                let mut delay = runtime::sleep(timeout).fuse();
+
+                select! {
+                    result = future => result,
+                    _ = delay => Err(PandaError::HandlerTimeout(name.to_string()).into()),
+                }
+            }
+            None => future.await,
+        };
+
+        if let Err(e) = result {
+            log::error!("Handler error in {}: {}", name, e);
+        };
+    });
+}
+
 /// This macro it's used to create all "on_EVENT" methods to add a event handler
 macro_rules! impl_on_event_fn {
     ($( $(#[$meta: meta])* pub fn $fn_name: ident($event_name: ident, $event: ty) ); *) => {
@@ -83,18 +117,13 @@ impl<S: Sync + Send> Client<S> {
             token.insert_str(0, "Bot ");
         }
 
-        let mut this = Client {
+        Ok(Client {
             handler: EventHandler::new(),
             config: Config::new_default(),
             token: token.clone(),
             session: Arc::new(SessionData::new(token, (), to_gateway_ch)),
             gateway,
-        };
-
-        // Send identify and spawn heartbeater
-        this.clean_connect().await;
-
-        Ok(this)
+        })
     }
 
     /// Create a new Panda Client with state
@@ -112,142 +141,242 @@ impl<S: Sync + Send> Client<S> {
             token.insert_str(0, "Bot ");
         }
 
-        let mut this = Self {
+        Ok(Self {
             handler: EventHandler::new(),
             config: Config::new_default(),
             token: token.clone(),
             session: Arc::new(SessionData::new(token, state, to_gateway_ch)),
             gateway,
-        };
-
-        // Send identify and spawn heartbeater
-        this.clean_connect().await;
-
-        Ok(this)
+        })
     }
 
     /// Create a new "discord" Client with personalized configs
     pub fn set_config(&mut self, config: config::ConfigBuilder) -> Result<()> {
         self.config = config.build();
 
+        if let Some((max_per_channel, ttl)) = self.config.message_cache {
+            self.session.cache.set_message_limits(max_per_channel, ttl);
+        }
+
+        if let Some(backend) = self.config.cache_backend.clone() {
+            self.session.cache.set_backend(backend);
+        }
+
+        if let Some(allowed_mentions) = self.config.allowed_mentions.clone() {
+            self.session.http.set_allowed_mentions(allowed_mentions);
+        }
+
         Ok(())
     }
 
-    /// Start the bot connection process
+    /// Start the bot connection process, it returns when [`SessionData::shutdown`] is called
+    ///
+    /// [`SessionData::shutdown`]: struct.SessionData.html#method.shutdown
     pub async fn start(&mut self) -> Result<()> {
+        let mut cancelled = self.session.cancellation.cancelled().fuse();
+
+        // Send IDENTIFY, or RESUME if there is a cache snapshot, and spawn heartbeater
+        self.connect().await;
+
         // Connection loop
         loop {
-            if let Some(event) = self.gateway.from_gateway.next().await {
+            let event = select! {
+                event = self.gateway.from_gateway.next() => event,
+                _ = cancelled => {
+                    log::info!("Client shutdown");
+                    self.save_cache().await;
+                    return Ok(());
+                }
+            };
+
+            if let Some(event) = event {
                 match event {
-                    Event::Dispatch(d) => match d {
-                        DispatchEvent::Ready(e) => {
-                            // Save SessionData id
-                            let id = e.session_id.clone();
-                            self.session.set_id(id).await;
-
-                            handle_event!(self, ready, e);
-                        }
-                        // Channel
-                        DispatchEvent::ChannelCreate(e) => {
-                            handle_event!(self, channel_create, e);
-                        }
-                        DispatchEvent::ChannelUpdate(e) => {
-                            handle_event!(self, channel_update, e);
-                        }
-                        DispatchEvent::ChannelDelete(e) => {
-                            handle_event!(self, channel_delete, e);
-                        }
-                        DispatchEvent::ChannelPinsUpdate(e) => {
-                            handle_event!(self, channel_pins_update, e);
-                        }
-                        // Guild
-                        DispatchEvent::GuildCreate(e) => {
-                            handle_event!(self, guild_create, e);
-                        }
-                        DispatchEvent::GuildUpdate(e) => {
-                            handle_event!(self, guild_update, e);
-                        }
-                        DispatchEvent::GuildDelete(e) => {
-                            handle_event!(self, guild_delete, e);
+                    Event::Dispatch(mut d) => {
+                        // Update the cache before calling the handlers
+                        let guild_status = self.session.cache.track_guild(&d);
+                        self.session.cache.update(&mut d);
+                        let cache_ready = self.session.cache.take_ready();
+                        let name = d.name();
+
+                        // Feed the collectors and the catch-all handler with all events
+                        self.session.collectors.dispatch(&d).await;
+
+                        if let Some(func) = &self.handler.event {
+                            let timeout = self.config.handler_timeout(name);
+                            spawn_handler(name, func(self.session.clone(), d.clone()), timeout);
                         }
-                        DispatchEvent::GuildBanAdd(e) => {
-                            handle_event!(self, guild_ban_add, e);
-                        }
-                        DispatchEvent::GuildBanRemove(e) => {
-                            handle_event!(self, guild_ban_remove, e);
-                        }
-                        DispatchEvent::GuildEmojisUpdate(e) => {
-                            handle_event!(self, guild_emojis_update, e);
-                        }
-                        DispatchEvent::GuildIntegrationsUpdate(e) => {
-                            handle_event!(self, guild_integrations_update, e);
-                        }
-                        DispatchEvent::GuildMemberAdd(e) => {
-                            handle_event!(self, guild_member_add, e);
-                        }
-                        DispatchEvent::GuildMemberUpdate(e) => {
-                            handle_event!(self, guild_member_update, e);
-                        }
-                        DispatchEvent::GuildMemberRemove(e) => {
-                            handle_event!(self, guild_member_remove, e);
-                        }
-                        DispatchEvent::GuildMembersChunk(e) => {
-                            handle_event!(self, guild_members_chunk, e);
-                        }
-                        DispatchEvent::GuildRoleCreate(e) => {
-                            handle_event!(self, guild_role_create, e);
-                        }
-                        DispatchEvent::GuildRoleUpdate(e) => {
-                            handle_event!(self, guild_role_update, e);
-                        }
-                        DispatchEvent::GuildRoleDelete(e) => {
-                            handle_event!(self, guild_role_delete, e);
-                        }
-                        // Message
-                        DispatchEvent::MessageCreate(e) => {
-                            handle_event!(self, message_create, e);
-                        }
-                        DispatchEvent::MessageUpdate(e) => {
-                            handle_event!(self, message_update, e);
-                        }
-                        DispatchEvent::MessageDelete(e) => {
-                            handle_event!(self, message_delete, e);
-                        }
-                        DispatchEvent::MessageDeleteBulk(e) => {
-                            handle_event!(self, message_delete_bulk, e);
-                        }
-                        DispatchEvent::MessageReactionAdd(e) => {
-                            handle_event!(self, message_reaction_add, e);
-                        }
-                        DispatchEvent::MessageReactionRemove(e) => {
-                            handle_event!(self, message_reaction_remove, e);
-                        }
-                        DispatchEvent::MessageReactionRemoveAll(e) => {
-                            handle_event!(self, message_reaction_remove_all, e);
-                        }
-                        DispatchEvent::MessageReactionRemoveEmoji(e) => {
-                            handle_event!(self, message_reaction_remove_emoji, e);
-                        }
-                        // Presences
-                        DispatchEvent::PresenceUpdate(e) => {
-                            handle_event!(self, presence_update, e);
-                        }
-                        DispatchEvent::TypingStart(e) => {
-                            handle_event!(self, typing_start, e);
+
+                        match d {
+                            DispatchEvent::Ready(e) => {
+                                // Save SessionData id
+                                let id = e.session_id.clone();
+                                self.session.set_id(id).await;
+
+                                handle_event!(self, name, ready, e);
+                            }
+                            DispatchEvent::Resumed(e) => {
+                                log::info!("Session resumed successfully");
+                                handle_event!(self, name, resumed, e);
+                            }
+                            // Channel
+                            DispatchEvent::ChannelCreate(e) => {
+                                handle_event!(self, name, channel_create, e);
+                            }
+                            DispatchEvent::ChannelUpdate(e) => {
+                                handle_event!(self, name, channel_update, e);
+                            }
+                            DispatchEvent::ChannelDelete(e) => {
+                                handle_event!(self, name, channel_delete, e);
+                            }
+                            DispatchEvent::ChannelPinsUpdate(e) => {
+                                handle_event!(self, name, channel_pins_update, e);
+                            }
+                            // Guild
+                            DispatchEvent::GuildCreate(e) => {
+                                match guild_status {
+                                    Some(GuildStatus::Available) => {
+                                        call_handler!(self, "GUILD_AVAILABLE", guild_available, e.clone())
+                                    }
+                                    Some(GuildStatus::Joined) => {
+                                        call_handler!(self, "GUILD_JOIN", guild_join, e.clone())
+                                    }
+                                    _ => {}
+                                }
+
+                                handle_event!(self, name, guild_create, e);
+                            }
+                            DispatchEvent::GuildUpdate(e) => {
+                                handle_event!(self, name, guild_update, e);
+                            }
+                            DispatchEvent::GuildDelete(e) => {
+                                match guild_status {
+                                    Some(GuildStatus::Unavailable) => {
+                                        call_handler!(self, "GUILD_UNAVAILABLE", guild_unavailable, e.clone())
+                                    }
+                                    Some(GuildStatus::Left) => {
+                                        call_handler!(self, "GUILD_LEAVE", guild_leave, e.clone())
+                                    }
+                                    _ => {}
+                                }
+
+                                handle_event!(self, name, guild_delete, e);
+                            }
+                            DispatchEvent::GuildBanAdd(e) => {
+                                handle_event!(self, name, guild_ban_add, e);
+                            }
+                            DispatchEvent::GuildBanRemove(e) => {
+                                handle_event!(self, name, guild_ban_remove, e);
+                            }
+                            DispatchEvent::GuildEmojisUpdate(e) => {
+                                handle_event!(self, name, guild_emojis_update, e);
+                            }
+                            DispatchEvent::GuildIntegrationsUpdate(e) => {
+                                handle_event!(self, name, guild_integrations_update, e);
+                            }
+                            DispatchEvent::GuildMemberAdd(e) => {
+                                handle_event!(self, name, guild_member_add, e);
+                            }
+                            DispatchEvent::GuildMemberUpdate(e) => {
+                                handle_event!(self, name, guild_member_update, e);
+                            }
+                            DispatchEvent::GuildMemberRemove(e) => {
+                                handle_event!(self, name, guild_member_remove, e);
+                            }
+                            DispatchEvent::GuildMembersChunk(e) => {
+                                handle_event!(self, name, guild_members_chunk, e);
+                            }
+                            DispatchEvent::GuildRoleCreate(e) => {
+                                handle_event!(self, name, guild_role_create, e);
+                            }
+                            DispatchEvent::GuildRoleUpdate(e) => {
+                                handle_event!(self, name, guild_role_update, e);
+                            }
+                            DispatchEvent::GuildRoleDelete(e) => {
+                                handle_event!(self, name, guild_role_delete, e);
+                            }
+                            // Invite
+                            DispatchEvent::InviteCreate(e) => {
+                                handle_event!(self, name, invite_create, e);
+                            }
+                            DispatchEvent::InviteDelete(e) => {
+                                handle_event!(self, name, invite_delete, e);
+                            }
+                            // Message
+                            DispatchEvent::MessageCreate(e) => {
+                                handle_event!(self, name, message_create, e);
+                            }
+                            DispatchEvent::MessageUpdate(e) => {
+                                handle_event!(self, name, message_update, e);
+                            }
+                            DispatchEvent::MessageDelete(e) => {
+                                handle_event!(self, name, message_delete, e);
+                            }
+                            DispatchEvent::MessageDeleteBulk(e) => {
+                                handle_event!(self, name, message_delete_bulk, e);
+                            }
+                            DispatchEvent::MessageReactionAdd(e) => {
+                                handle_event!(self, name, message_reaction_add, e);
+                            }
+                            DispatchEvent::MessageReactionRemove(e) => {
+                                handle_event!(self, name, message_reaction_remove, e);
+                            }
+                            DispatchEvent::MessageReactionRemoveAll(e) => {
+                                handle_event!(self, name, message_reaction_remove_all, e);
+                            }
+                            DispatchEvent::MessageReactionRemoveEmoji(e) => {
+                                handle_event!(self, name, message_reaction_remove_emoji, e);
+                            }
+                            // Presences
+                            DispatchEvent::PresenceUpdate(e) => {
+                                handle_event!(self, name, presence_update, e);
+                            }
+                            DispatchEvent::TypingStart(e) => {
+                                handle_event!(self, name, typing_start, e);
+                            }
+                            DispatchEvent::UserUpdate(e) => {
+                                handle_event!(self, name, user_update, e);
+                            }
+                            // Voice
+                            DispatchEvent::VoiceStateUpdate(e) => {
+                                handle_event!(self, name, voice_state_update, e);
+                            }
+                            DispatchEvent::VoiceServerUpdate(e) => {
+                                handle_event!(self, name, voice_server_update, e);
+                            }
+                            // Webhooks
+                            DispatchEvent::WebhooksUpdate(e) => {
+                                handle_event!(self, name, webhooks_update, e);
+                            }
+                            DispatchEvent::Reconnect => {}
                         }
-                        DispatchEvent::UserUpdate(e) => {
-                            handle_event!(self, user_update, e);
+
+                        // Sent after the handler of the last guild received
+                        if let Some(e) = cache_ready {
+                            handle_event!(self, "CACHE_READY", cache_ready, e);
                         }
-                        _ => {}
-                    },
+                    }
                     Event::Reconnect => {
                         log::info!("Reconnected successfully!");
                     }
                     Event::InvalidSession(resumable) => {
                         self.session.set_resumable(resumable);
+
+                        // The session can't be resumed, like an expired session of a snapshot,
+                        // so a new one is started
+                        if !resumable {
+                            runtime::sleep(Duration::from_secs(2)).await;
+                            self.identify().await;
+                            self.session.set_resumable(true);
+                        }
                     }
                     Event::HeartbeatACK => log::info!("HeartbeatACK received"),
                     Event::Close(error) => {
+                        // The connection was closed by a shutdown
+                        if self.session.cancellation.is_cancelled() {
+                            self.save_cache().await;
+                            return Ok(());
+                        }
+
                         log::error!("Error detected {}", error);
 
                         // Return if there are unrecoverable errors
@@ -265,8 +394,6 @@ impl<S: Sync + Send> Client<S> {
                 };
             }
         }
-
-        //Ok(())
     }
 
     /// Makes all necessary to reconnect to gateway
@@ -290,7 +417,79 @@ impl<S: Sync + Send> Client<S> {
         }
     }
 
+    /// Connect for the first time, if there is a cache snapshot the cache is loaded
+    /// and its session resumed
+    async fn connect(&mut self) {
+        let snapshot = match &self.config.cache_snapshot {
+            Some(path) => match CacheSnapshot::load(path).await {
+                Ok(snapshot) => Some(snapshot),
+                // There is no snapshot the first time
+                Err(PandaError::IoError(e)) if e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    log::error!("Couldn't load the cache snapshot: {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        if let Some(mut snapshot) = snapshot {
+            let session = snapshot.session_id.take();
+            let sequence = snapshot.sequence.take();
+            self.session.cache.import(snapshot);
+
+            // Resume the session of the snapshot, if it expired the gateway will
+            // send INVALID_SESSION and a new one will be started
+            if let (Some(session_id), Some(sequence)) = (session, sequence) {
+                log::info!("Resuming session from cache snapshot");
+                self.session.set_id(session_id).await;
+                self.gateway.set_last_sequence(sequence);
+                self.resume_connect(Some(sequence)).await;
+
+                return;
+            }
+        }
+
+        self.clean_connect().await;
+    }
+
+    /// Save the cache before the client stops, the snapshot and the changes that the backend
+    /// didn't save yet
+    async fn save_cache(&self) {
+        self.save_snapshot().await;
+
+        // Backends like FileBackend write to the disk
+        let backend = self.session.cache.backend();
+        if let Err(e) = runtime::spawn_blocking(move || backend.flush()).await {
+            log::error!("Couldn't save the cache: {}", e);
+        }
+    }
+
+    /// Save the cache snapshot with the current session, if it's enabled
+    async fn save_snapshot(&self) {
+        let path = match &self.config.cache_snapshot {
+            Some(path) => path,
+            None => return,
+        };
+
+        let mut snapshot = self.session.cache.export();
+        snapshot.session_id = Some(self.session.id().await);
+        snapshot.sequence = self.gateway.last_sequence();
+
+        if let Err(e) = snapshot.save(path).await {
+            log::error!("Couldn't save the cache snapshot: {}", e);
+        }
+    }
+
     async fn clean_connect(&mut self) {
+        self.identify().await;
+
+        // Spawn heartbeater
+        self.spawn_heartbeater();
+    }
+
+    /// Send an IDENTIFY command
+    async fn identify(&mut self) {
         // Create IDENTIFY
         let shard = [self.config.gateway_shard_id, self.config.gateway_num_shards];
         let identify = Command::new_identify(
@@ -306,9 +505,6 @@ impl<S: Sync + Send> Client<S> {
             .send(identify)
             .await
             .expect("Could't send identify");
-
-        // Spawn heartbeater
-        self.spawn_heartbeater();
     }
 
     async fn resume_connect(&mut self, last_sequence: Option<u64>) {
@@ -342,11 +538,22 @@ impl<S: Sync + Send> Client<S> {
 
     // Implementations of all on_DiscordEvent
     impl_on_event_fn! {
+        /// Set the handler function that receives every dispatched event, as a [`DispatchEvent`].
+        /// It runs in addition to the handler of each event.
+        ///
+        /// [`DispatchEvent`]: ../models/gateway/events/enum.DispatchEvent.html
+        pub fn on_event(event, DispatchEvent);
+
         /// Set the handler function for [`Ready`] event
         ///
         /// [`Ready`]: ../models/gateway/events/struct.Ready.html
         pub fn on_ready(ready, Ready);
 
+        /// Set the handler function for [`Resumed`] event
+        ///
+        /// [`Resumed`]: ../models/gateway/events/struct.Resumed.html
+        pub fn on_resumed(resumed, Resumed);
+
 
         // *******************************************************************************
         // * CHANNEL METHODS
@@ -449,6 +656,55 @@ impl<S: Sync + Send> Client<S> {
         /// [`GuildRoleDelete`]: ../models/gateway/events/struct.GuildRoleDelete.html
         pub fn on_guild_role_delete(guild_role_delete, GuildRoleDelete);
 
+        /// Set the handler function for guilds received after [`Ready`] event or recovered from
+        /// an outage, it receives the [`GuildCreate`] event and runs before `on_guild_create`
+        ///
+        /// [`Ready`]: ../models/gateway/events/struct.Ready.html
+        /// [`GuildCreate`]: ../models/gateway/events/struct.GuildCreate.html
+        pub fn on_guild_available(guild_available, GuildCreate);
+
+        /// Set the handler function for guilds that the bot joined, it receives the [`GuildCreate`]
+        /// event and runs before `on_guild_create`
+        ///
+        /// [`GuildCreate`]: ../models/gateway/events/struct.GuildCreate.html
+        pub fn on_guild_join(guild_join, GuildCreate);
+
+        /// Set the handler function for guilds that became unavailable because of an outage, it
+        /// receives the [`GuildDelete`] event and runs before `on_guild_delete`
+        ///
+        /// [`GuildDelete`]: ../models/gateway/events/struct.GuildDelete.html
+        pub fn on_guild_unavailable(guild_unavailable, GuildDelete);
+
+        /// Set the handler function for guilds that the bot left or was removed from, it
+        /// receives the [`GuildDelete`] event and runs before `on_guild_delete`
+        ///
+        /// [`GuildDelete`]: ../models/gateway/events/struct.GuildDelete.html
+        pub fn on_guild_leave(guild_leave, GuildDelete);
+
+        /// Set the handler function for [`CacheReady`] event, it's sent once all guilds of the
+        /// [`Ready`] event have been received and saved in the cache. When the cache is loaded
+        /// from a snapshot and the session is resumed, it's sent with the first event after the resume.
+        ///
+        /// [`CacheReady`]: ../cache/struct.CacheReady.html
+        /// [`Ready`]: ../models/gateway/events/struct.Ready.html
+        pub fn on_cache_ready(cache_ready, CacheReady);
+
+
+        // *******************************************************************************
+        // * INVITE METHODS
+        // *******************************************************************************
+
+
+        /// Set the handler function for [`InviteCreate`] event
+        ///
+        /// [`InviteCreate`]: ../models/gateway/events/struct.InviteCreate.html
+        pub fn on_invite_create(invite_create, InviteCreate);
+
+        /// Set the handler function for [`InviteDelete`] event
+        ///
+        /// [`InviteDelete`]: ../models/gateway/events/struct.InviteDelete.html
+        pub fn on_invite_delete(invite_delete, InviteDelete);
+
 
         // *******************************************************************************
         // * MESSAGE METHODS
@@ -514,6 +770,33 @@ impl<S: Sync + Send> Client<S> {
         /// Set the handler function for [`UserUpdate`] event
         ///
         /// [`UserUpdate`]: ../models/gateway/events/struct.UserUpdate.html
-        pub fn on_user_update(user_update, UserUpdate)
+        pub fn on_user_update(user_update, UserUpdate);
+
+
+        // *******************************************************************************
+        // * VOICE METHODS
+        // *******************************************************************************
+
+
+        /// Set the handler function for [`VoiceStateUpdate`] event
+        ///
+        /// [`VoiceStateUpdate`]: ../models/gateway/events/struct.VoiceStateUpdate.html
+        pub fn on_voice_state_update(voice_state_update, VoiceStateUpdate);
+
+        /// Set the handler function for [`VoiceServerUpdate`] event
+        ///
+        /// [`VoiceServerUpdate`]: ../models/gateway/events/struct.VoiceServerUpdate.html
+        pub fn on_voice_server_update(voice_server_update, VoiceServerUpdate);
+
+
+        // *******************************************************************************
+        // * WEBHOOKS METHODS
+        // *******************************************************************************
+
+
+        /// Set the handler function for [`WebhooksUpdate`] event
+        ///
+        /// [`WebhooksUpdate`]: ../models/gateway/events/struct.WebhooksUpdate.html
+        pub fn on_webhooks_update(webhooks_update, WebhooksUpdate)
     }
 }
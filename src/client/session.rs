@@ -1,31 +1,52 @@
 //! SessionData
 
+use super::{
+    cancellation::CancellationToken,
+    collector::Collectors,
+};
 use crate::{
+    cache::Cache,
     error::{Result, PandaError},
     models::{ gateway::commands::Command, user::StatusUpdate },
     HttpClient};
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use futures::{ channel::mpsc::UnboundedSender, lock::Mutex, sink::SinkExt };
+use futures::{ channel::mpsc::UnboundedSender, lock::Mutex, sink::SinkExt, stream::{BoxStream, StreamExt} };
 
 /// The struct of the current session of the bot.
 pub struct SessionData<S> {
     id: Mutex<String>,
     pub http: HttpClient,
     pub state: S,
+    /// Cache of guilds, channels, roles and members, updated with every event
+    pub cache: Arc<Cache>,
+    /// Cancelled when the client is shutting down, handlers can watch it to stop their work
+    pub cancellation: CancellationToken,
     is_resumable: AtomicBool,
-    to_gateway_ch: Mutex<UnboundedSender<Command>>
+    to_gateway_ch: Mutex<UnboundedSender<Command>>,
+    pub(crate) collectors: Collectors,
 }
 
 impl<S> SessionData<S> {
     pub(crate) fn new(token: String, state: S, to_gateway_ch: UnboundedSender<Command>) -> Self {
+        let cache = Arc::new(Cache::new());
+
         SessionData {
             id: Mutex::new("".into()),
-            http: HttpClient::new(token),
+            http: HttpClient::new(token).with_cache(cache.clone()),
             state,
+            cache,
+            cancellation: CancellationToken::new(),
             is_resumable: AtomicBool::new(true),
-            to_gateway_ch: Mutex::new(to_gateway_ch)
+            to_gateway_ch: Mutex::new(to_gateway_ch),
+            collectors: Collectors::default(),
         }
     }
 
@@ -61,4 +82,59 @@ impl<S> SessionData<S> {
 
         Ok(())
     }
+
+    /// Shutdown the client, it cancels the [`CancellationToken`] and closes the gateway
+    /// connection. [`Client::start`] will return after this.
+    ///
+    /// [`CancellationToken`]: struct.CancellationToken.html
+    /// [`Client::start`]: struct.Client.html#method.start
+    pub async fn shutdown(&self) -> Result<()> {
+        self.cancellation.cancel();
+
+        self.to_gateway_ch
+            .lock()
+            .await
+            .send(Command::Close)
+            .await
+            .map_err(|_| PandaError::ConnectionClosed)?;
+
+        Ok(())
+    }
+
+    /// Wait for the next event of type `E` that matches the predicate. Returns `None` if
+    /// the timeout is reached before.
+    ///
+    /// ```ignore
+    /// let answer = session
+    ///     .wait_for::<MessageCreate>(move |m| m.author.id == author_id, Duration::from_secs(30))
+    ///     .await;
+    /// ```
+    pub async fn wait_for<E>(
+        &self,
+        predicate: impl Fn(&E) -> bool + Send + Sync + 'static,
+        timeout: Duration,
+    ) -> Option<E>
+    where
+        E: Clone + Send + Sync + 'static,
+    {
+        let receiver = self.collectors.register(predicate, Some(1)).await;
+
+        self.collectors.with_timeout(receiver, timeout).next().await
+    }
+
+    /// Returns a stream of the next events of type `E` that match the predicate. The stream
+    /// ends when `max` events were received or when the timeout is reached, it's empty if `max` is 0.
+    pub async fn collect<E>(
+        &self,
+        predicate: impl Fn(&E) -> bool + Send + Sync + 'static,
+        max: usize,
+        timeout: Duration,
+    ) -> BoxStream<'static, E>
+    where
+        E: Clone + Send + Sync + 'static,
+    {
+        let receiver = self.collectors.register(predicate, Some(max)).await;
+
+        self.collectors.with_timeout(receiver, timeout)
+    }
 }
@@ -3,6 +3,7 @@
 //! Here there are some wrappers of [Discord API errors](https://discordapp.com/developers/docs/topics/opcodes-and-status-codes)
 
 use async_tungstenite::tungstenite::Error as TungsteniteError;
+use serde_json::Value;
 use std::{error::Error, fmt, result::Result as StdResult};
 
 /// This library use a shared result type, because all functions returns the same error type
@@ -48,15 +49,15 @@ pub enum PandaError {
     /// Returned when http request format was invalid
     HttpImproperlyFormatted,
 
-    /// Returned when http request has an invalid token
-    HttpUnauthorized,
+    /// Returned when Discord API responds with an error status, like when the token is invalid,
+    /// the client doesn't have enough permissions or the request has invalid parameters
+    Http(HttpError),
 
-    /// Returned when the client doesn't have enough permissions
-    HttpForbidden,
+    /// Returned when a request is still rate limited after being retried
+    HttpRateLimited,
 
-    /// Returned when the http request URL had invalid parameters,
-    /// such as wrong {channel_id}
-    HttpInvalidParameters,
+    /// Returned when an event handler exceeded its timeout, it contains the event name
+    HandlerTimeout(String),
 
     /// Returned when the gateway couldn't close the connection succesfully
     UnsuccessfulConnectionClose,
@@ -76,6 +77,15 @@ pub enum PandaError {
     /// tungstenite
     TungsteniteError(TungsteniteError),
 
+    /// Returned when a file couldn't be read or written, like a cache snapshot
+    IoError(std::io::Error),
+
+    /// Returned when an image to upload is not a PNG, JPEG or GIF
+    UnsupportedImageFormat,
+
+    /// Returned when a webhook URL doesn't contain the ID and the token of the webhook
+    InvalidWebhookUrl,
+
     RuntimeError,
 }
 
@@ -91,15 +101,18 @@ impl fmt::Display for PandaError {
             Self::WrongCompression => write!(f, "Wrong zlib compression"),
             Self::HttpNoResponse => write!(f, "Discord HTTP API didn't response"),
             Self::HttpImproperlyFormatted => write!(f, "Invalid format of request body"),
-            Self::HttpUnauthorized => write!(f, "The client token is invalid"),
-            Self::HttpForbidden => write!(f, "The client did not have permission to the resource"),
-            Self::HttpInvalidParameters => write!(f, "The request had invalid parameters"),
+            Self::Http(e) => write!(f, "{}", e),
+            Self::HttpRateLimited => write!(f, "The request was rate limited too many times"),
+            Self::HandlerTimeout(e) => write!(f, "The handler of {} exceeded its timeout", e),
             Self::UnsuccessfulConnectionClose => write!(f, "The gateway couldn't close succesfully the connection"),
             Self::InvalidShard => write!(f, "You sent an invalid shard"),
             Self::ShardingRequired => write!(f, "The SessionData would have handled too many guilds - you are required to shard your connection in order to connect."),
             Self::InvalidApiGatewayVersion => write!(f, "panda needs to update the gateway version"),
             Self::SerdeError(e) => write!(f, "Serde Error: {}", e),
             Self::TungsteniteError(e) => write!(f, "Tungstenite Error: {}", e),
+            Self::IoError(e) => write!(f, "IO Error: {}", e),
+            Self::UnsupportedImageFormat => write!(f, "The image is not a PNG, JPEG or GIF"),
+            Self::InvalidWebhookUrl => write!(f, "The webhook URL is invalid"),
             Self::UnknownOpcodeSent => write!(f, "panda sent an invalid Opcode, please report the bug"),
             Self::InvalidDecodeSent => write!(f, "panda sent an invalid payload, please report the bug"),
             Self::RuntimeError => write!(f, "runtime error")
@@ -109,6 +122,192 @@ impl fmt::Display for PandaError {
 
 impl Error for PandaError {}
 
+/// An error response of Discord API, with the JSON error body sent by Discord
+#[derive(Debug)]
+pub struct HttpError {
+    /// HTTP status code of the response, like 403
+    pub status: u16,
+    /// HTTP method of the request
+    pub method: String,
+    /// Route of the request without IDs, like `/api/v6/channels/{id}/messages`
+    pub route: String,
+    /// Discord error code, it's `None` if the response didn't have a JSON error body
+    pub code: Option<DiscordErrorCode>,
+    /// Discord error message
+    pub message: Option<String>,
+    /// Validation errors of the fields of the request body
+    pub errors: Vec<FieldError>,
+}
+
+/// A validation error of a field of the request body, like a too long message content
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldError {
+    /// Path of the field, like `embed.fields.0.name`
+    pub path: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// [Discord JSON error codes](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json),
+/// the codes without a variant are kept in `Other`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiscordErrorCode {
+    General,
+    UnknownAccount,
+    UnknownApplication,
+    UnknownChannel,
+    UnknownGuild,
+    UnknownIntegration,
+    UnknownInvite,
+    UnknownMember,
+    UnknownMessage,
+    UnknownOverwrite,
+    UnknownRole,
+    UnknownToken,
+    UnknownUser,
+    UnknownEmoji,
+    UnknownWebhook,
+    UnknownBan,
+    BotsCannotUseEndpoint,
+    OnlyBotsCanUseEndpoint,
+    MaxGuildsReached,
+    MaxPinsReached,
+    MaxRolesReached,
+    MaxWebhooksReached,
+    MaxReactionsReached,
+    MaxChannelsReached,
+    Unauthorized,
+    MissingAccess,
+    InvalidAccountType,
+    CannotExecuteOnDmChannel,
+    CannotEditMessageByOtherUser,
+    CannotSendEmptyMessage,
+    CannotSendMessagesToUser,
+    CannotSendMessagesInVoiceChannel,
+    MissingPermissions,
+    InvalidAuthenticationToken,
+    NoteTooLong,
+    InvalidBulkDeleteCount,
+    InvalidChannelType,
+    MessageTooOldToBulkDelete,
+    InvalidFormBody,
+    ReactionBlocked,
+    Other(u64),
+}
+
+impl From<u64> for DiscordErrorCode {
+    fn from(code: u64) -> Self {
+        match code {
+            0 => Self::General,
+            10001 => Self::UnknownAccount,
+            10002 => Self::UnknownApplication,
+            10003 => Self::UnknownChannel,
+            10004 => Self::UnknownGuild,
+            10005 => Self::UnknownIntegration,
+            10006 => Self::UnknownInvite,
+            10007 => Self::UnknownMember,
+            10008 => Self::UnknownMessage,
+            10009 => Self::UnknownOverwrite,
+            10011 => Self::UnknownRole,
+            10012 => Self::UnknownToken,
+            10013 => Self::UnknownUser,
+            10014 => Self::UnknownEmoji,
+            10015 => Self::UnknownWebhook,
+            10026 => Self::UnknownBan,
+            20001 => Self::BotsCannotUseEndpoint,
+            20002 => Self::OnlyBotsCanUseEndpoint,
+            30001 => Self::MaxGuildsReached,
+            30003 => Self::MaxPinsReached,
+            30005 => Self::MaxRolesReached,
+            30007 => Self::MaxWebhooksReached,
+            30010 => Self::MaxReactionsReached,
+            30013 => Self::MaxChannelsReached,
+            40001 => Self::Unauthorized,
+            50001 => Self::MissingAccess,
+            50002 => Self::InvalidAccountType,
+            50003 => Self::CannotExecuteOnDmChannel,
+            50005 => Self::CannotEditMessageByOtherUser,
+            50006 => Self::CannotSendEmptyMessage,
+            50007 => Self::CannotSendMessagesToUser,
+            50008 => Self::CannotSendMessagesInVoiceChannel,
+            50013 => Self::MissingPermissions,
+            50014 => Self::InvalidAuthenticationToken,
+            50015 => Self::NoteTooLong,
+            50016 => Self::InvalidBulkDeleteCount,
+            50024 => Self::InvalidChannelType,
+            50034 => Self::MessageTooOldToBulkDelete,
+            50035 => Self::InvalidFormBody,
+            90001 => Self::ReactionBlocked,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl HttpError {
+    /// Create the error of a response, `body` is parsed as a Discord JSON error body if possible
+    pub(crate) fn new(status: u16, method: impl Into<String>, route: impl Into<String>, body: &[u8]) -> Self {
+        let body: Value = serde_json::from_slice(body).unwrap_or_default();
+
+        let mut errors = Vec::new();
+        collect_field_errors(&body["errors"], &mut Vec::new(), &mut errors);
+
+        HttpError {
+            status,
+            method: method.into(),
+            route: route.into(),
+            code: body["code"].as_u64().map(DiscordErrorCode::from),
+            message: body["message"].as_str().map(String::from),
+            errors,
+        }
+    }
+}
+
+// The errors are nested by field, like {"embed": {"title": {"_errors": [{"code": "...", "message": "..."}]}}}
+fn collect_field_errors<'a>(value: &'a Value, path: &mut Vec<&'a str>, errors: &mut Vec<FieldError>) {
+    let object = match value.as_object() {
+        Some(object) => object,
+        None => return,
+    };
+
+    for (key, value) in object {
+        if key == "_errors" {
+            for error in value.as_array().into_iter().flatten() {
+                errors.push(FieldError {
+                    path: path.join("."),
+                    code: error["code"].as_str().unwrap_or_default().to_string(),
+                    message: error["message"].as_str().unwrap_or_default().to_string(),
+                });
+            }
+        } else {
+            path.push(key);
+            collect_field_errors(value, path, errors);
+            path.pop();
+        }
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} failed with status {}", self.method, self.route, self.status)?;
+
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+
+        if let Some(code) = &self.code {
+            write!(f, " ({:?})", code)?;
+        }
+
+        for error in &self.errors {
+            write!(f, "\n- {}: {}", error.path, error.message)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for HttpError {}
+
 // Error parsing
 impl From<serde_json::Error> for PandaError {
     fn from(error: serde_json::Error) -> Self {
@@ -134,6 +333,18 @@ impl From<TungsteniteError> for PandaError {
     }
 }
 
+impl From<std::io::Error> for PandaError {
+    fn from(error: std::io::Error) -> Self {
+        PandaError::IoError(error)
+    }
+}
+
+impl From<isahc::http::Error> for PandaError {
+    fn from(_error: isahc::http::Error) -> Self {
+        PandaError::HttpImproperlyFormatted
+    }
+}
+
 impl From<isahc::Error> for PandaError {
     fn from(_error: isahc::Error) -> Self {
         // TODO: add match
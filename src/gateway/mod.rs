@@ -67,6 +67,19 @@ impl GatewayConnection {
         })
     }
 
+    /// Returns the sequence of the last event received
+    pub(crate) fn last_sequence(&self) -> Option<u64> {
+        match self.last_sequence.load(Ordering::Relaxed) {
+            0 => None,
+            seq => Some(seq),
+        }
+    }
+
+    /// Set the last sequence, used when a session is resumed from a snapshot
+    pub(crate) fn set_last_sequence(&self, seq: u64) {
+        self.last_sequence.store(seq, Ordering::Relaxed);
+    }
+
     pub(crate) fn close_channels(&mut self) -> Result<()> {
         self.from_gateway.close();
         self.to_gateway.close_channel();
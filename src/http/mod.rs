@@ -1,29 +1,154 @@
+mod multipart;
 mod rate_limit;
 mod routing;
+mod webhook;
 
+#[cfg(test)]
+mod tests;
+
+use multipart::Multipart;
 use rate_limit::RateLimit;
-use routing::Route;
+use routing::{encode, Route};
+
+pub use multipart::AttachmentFile;
+pub use webhook::WebhookClient;
 
 use crate::{
-    error::{PandaError, Result},
+    cache::Cache,
+    error::{HttpError, PandaError, Result},
     models::{
-        channel::{Channel, Embed, Message},
+        channel::{AllowedMentions, Channel, Embed, Message, Overwrite},
+        emoji::Emoji,
+        guild::{AuditLog, Ban, Guild, GuildMember, GuildPreview, GuildWidget, PartialGuild, Role, VanityUrl},
+        invite::Invite,
         user::User,
+        voice::VoiceRegion,
+        webhook::Webhook,
+    },
+    runtime,
+    utils::{
+        builders::{AuditLogFilter, CreateMessage, GuildPrune},
+        image_data_uri,
     },
 };
 
+use futures::io::AsyncReadExt;
 use isahc::{
     http::{Method, StatusCode},
     prelude::*,
     HttpClient as IsachClient,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+use url::form_urlencoded::Serializer;
+
+/// Response of the prune endpoints
+#[derive(Deserialize)]
+struct PruneCount<T> {
+    pruned: T,
+}
 
 /// It's the http client of panda, it have methods to make requests to all routes
+///
+/// Cloning it is cheap, the clones share the connection pool and the rate limits
+#[derive(Clone)]
 pub struct HttpClient {
     token: String,
     client: IsachClient,
     rate_limit: RateLimit,
+    // Used to save the sent messages in the message cache
+    cache: Option<Arc<Cache>>,
+    // Sent as X-Audit-Log-Reason header
+    reason: Option<String>,
+    // Used when a new message doesn't have allowed mentions
+    allowed_mentions: Arc<RwLock<Option<AllowedMentions>>>,
+}
+
+/// Make a request to the Discord API, waiting first if the rate limit of the route was reached.
+/// The `Authorization` header is only sent if there is a token, webhooks don't need it, and the
+/// `X-Audit-Log-Reason` header is only sent by the requests that modify something.
+///
+/// If Discord responds with a 429 the request is sent again after the rate limit ends, up to
+/// `MAX_RETRIES` times. Any other error status is returned as a [`PandaError::Http`], so the callers
+/// only deserialize successful responses.
+///
+/// [`PandaError::Http`]: ../../panda/enum.PandaError.html#variant.Http
+pub(crate) async fn make_request<B: Into<Body>>(
+    client: &IsachClient,
+    rate_limit: &RateLimit,
+    token: Option<&str>,
+    reason: Option<&str>,
+    content_type: &str,
+    route: Route<B>,
+) -> Result<Response<Body>> {
+    let route_key = rate_limit::route_key(&route.method, &route.uri);
+
+    // Keep the body, it's sent again if the request is retried
+    let mut body = Vec::new();
+    let mut route_body: Body = route.body.into();
+    route_body.read_to_end(&mut body).await?;
+
+    let mut retries = 0;
+
+    loop {
+        // Check and wait if we reach the limit
+        rate_limit.acquire(&route_key, &route.bucket_key).await;
+
+        let mut request = Request::builder()
+            .method(route.method.clone())
+            .uri(&route.uri)
+            .header("X-RateLimit-Precision", "millisecond");
+
+        if let Some(token) = token {
+            request = request.header("Authorization", token);
+        }
+
+        if let Some(reason) = reason {
+            if route.method != Method::GET {
+                request = request.header("X-Audit-Log-Reason", encode(reason));
+            }
+        }
+
+        let request = if body.is_empty() {
+            request.body(Body::empty())?
+        } else {
+            request
+                .header("Content-Type", content_type)
+                .body(Body::from(body.clone()))?
+        };
+
+        // Get response
+        let response = client.send_async(request).await?;
+
+        // Update the limit with the response headers
+        match rate_limit.update(&route_key, &route.bucket_key, &response).await {
+            None if response.status().is_success() || response.status() == StatusCode::NOT_MODIFIED => {
+                return Ok(response)
+            }
+            None => return Err(http_error(&route_key, response).await),
+            Some(wait) if retries < rate_limit::MAX_RETRIES => {
+                log::warn!("Rate limited on {}, retrying in {:?}", route_key, wait);
+
+                retries += 1;
+                runtime::sleep(wait).await;
+            }
+            Some(_) => return Err(PandaError::HttpRateLimited),
+        }
+    }
+}
+
+/// Create the error of a response with an error status, with the error body sent by Discord
+async fn http_error(route_key: &str, mut response: Response<Body>) -> PandaError {
+    let mut body = Vec::new();
+
+    // A body that can't be read is handled like a body without a Discord error
+    if let Err(e) = response.body_mut().read_to_end(&mut body).await {
+        log::warn!("Can't read the error body of {}: {}", route_key, e);
+    }
+
+    let (method, route) = route_key.split_at(route_key.find(' ').unwrap_or(0));
+
+    PandaError::Http(HttpError::new(response.status().as_u16(), method, route.trim(), &body))
 }
 
 impl HttpClient {
@@ -34,68 +159,82 @@ impl HttpClient {
             token: token.into(),
             client,
             rate_limit: RateLimit::default(),
+            cache: None,
+            reason: None,
+            allowed_mentions: Arc::default(),
         }
     }
 
-    async fn _make_request<B: Into<Body>>(&self, route: Route<B>) -> Result<Response<Body>> {
-        // Check and wait if we reach the limit
-        self.rate_limit.check_and_sleep(&route.bucket_key).await;
-
-        // TODO: Improve this
-        let response = match route.method {
-            Method::GET | Method::PUT | Method::DELETE => {
-                let request = Request::builder()
-                    .method(route.method)
-                    .uri(&route.uri)
-                    .header("Authorization", &self.token)
-                    .body(())
-                    .unwrap();
-
-                // Get response
-                self.client.send_async(request).await?
-            }
-            Method::POST | Method::PATCH => {
-                let request = Request::builder()
-                    .method(route.method)
-                    .uri(&route.uri)
-                    .header("Authorization", &self.token)
-                    .header("Content-Type", "application/json")
-                    .body(route.body)
-                    .unwrap();
-
-                // Get response
-                self.client.send_async(request).await?
+    /// Set the default [`AllowedMentions`] of the new messages, it's used when a message doesn't
+    /// have its own allowed mentions. It's shared with the clones of the client.
+    ///
+    /// [`AllowedMentions`]: ../../panda/models/channel/struct.AllowedMentions.html
+    pub fn set_allowed_mentions(&self, allowed_mentions: AllowedMentions) {
+        *self.allowed_mentions.write().unwrap() = Some(allowed_mentions);
+    }
+
+    /// Add the default allowed mentions to the body of a message, if it doesn't have them
+    fn apply_allowed_mentions(&self, body: &mut serde_json::Value) {
+        let allowed_mentions = self.allowed_mentions.read().unwrap();
+
+        if let (Some(default), Some(body)) = (allowed_mentions.as_ref(), body.as_object_mut()) {
+            if !body.contains_key("allowed_mentions") {
+                body.insert("allowed_mentions".into(), serde_json::json!(default));
             }
-            _ => unimplemented!(),
-        };
+        }
+    }
 
-        // Update the limit with the response headers
-        self.rate_limit.update(route.bucket_key, &response).await;
-
-        Ok(response)
-    }
-
-    // TODO: Rename this and improve
-    fn _catch_http_errors(&self, res: &Response<Body>) -> Result<()> {
-        let err = match res.status() {
-            StatusCode::OK
-            | StatusCode::CREATED
-            | StatusCode::NO_CONTENT
-            | StatusCode::NOT_MODIFIED
-            | StatusCode::TOO_MANY_REQUESTS => return Ok(()),
-
-            StatusCode::BAD_REQUEST => PandaError::HttpImproperlyFormatted,
-            StatusCode::FORBIDDEN => PandaError::HttpForbidden, // no autorizado
-            StatusCode::NOT_FOUND => PandaError::HttpInvalidParameters, // not found or bad format
-            StatusCode::METHOD_NOT_ALLOWED => PandaError::HttpNoResponse, // method not allowed
-            // HANDLED BY RATELIMIT StatusCode::TOO_MANY_REQUESTS => PandaError::HttpNoResponse,
-            StatusCode::BAD_GATEWAY => PandaError::HttpNoResponse, // gateway unavailable
-            _ => PandaError::HttpNoResponse,
-        };
+    /// Returns a copy of the client that sends a reason with all requests that modify something,
+    /// it's shown in the audit log of the guild. The rate limits are shared with the original client.
+    ///
+    /// ```ignore
+    /// http.reason("Spam").kick_member(guild_id, user_id).await?;
+    /// ```
+    pub fn reason(&self, reason: impl Into<String>) -> HttpClient {
+        let mut client = self.clone();
+        client.reason = Some(reason.into());
+
+        client
+    }
+
+    /// Save all sent messages in the message cache
+    pub(crate) fn with_cache(mut self, cache: Arc<Cache>) -> Self {
+        self.cache = Some(cache);
+
+        self
+    }
+
+    /// Save the message in the message cache, if there is one
+    fn cache_message(&self, message: &Message) {
+        if let Some(cache) = &self.cache {
+            cache.insert_message(message.clone());
+        }
+    }
 
-        Err(err)
+    async fn _make_request<B: Into<Body>>(&self, route: Route<B>) -> Result<Response<Body>> {
+        make_request(
+            &self.client,
+            &self.rate_limit,
+            Some(&self.token),
+            self.reason.as_deref(),
+            "application/json",
+            route,
+        )
+        .await
+    }
 
-        //TODO: Remove HttpNoResponse here
+    async fn _make_multipart_request(&self, route: Route<Multipart>) -> Result<Response<Body>> {
+        let content_type = route.body.content_type();
+
+        make_request(
+            &self.client,
+            &self.rate_limit,
+            Some(&self.token),
+            self.reason.as_deref(),
+            &content_type,
+            route,
+        )
+        .await
     }
 
     // *******************************************************************************
@@ -220,10 +359,11 @@ impl HttpClient {
     /// [`MessageCreate`]: ../../panda/models/gateway/events/struct.MessageCreate.html
     pub async fn send_message(&self, channel_id: impl AsRef<str>, content: impl AsRef<str>) -> Result<Message> {
         // Create message body
-        let body = serde_json::json!({
+        let mut body = serde_json::json!({
             "content": content.as_ref(),
             "tts": false
         });
+        self.apply_allowed_mentions(&mut body);
         // Parse to a valid Body, isahc::Body
         let body = serde_json::to_string(&body)?;
 
@@ -231,7 +371,10 @@ impl HttpClient {
         let route = Route::create_message(channel_id, body);
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        let message = res.json()?;
+        self.cache_message(&message);
+
+        Ok(message)
     }
 
     /// Creates a new message, and returns the [`Message`]. This will also trigger
@@ -240,10 +383,11 @@ impl HttpClient {
     /// [`Message`]: ../../panda/models/channel/struct.Message.html
     /// [`MessageCreate`]: ../../panda/models/gateway/events/struct.MessageCreate.html
     pub async fn send_embed(&self, channel_id: impl AsRef<str>, embed: Embed) -> Result<Message> {
-        let body = serde_json::json!({
+        let mut body = serde_json::json!({
             "embed": embed,
             "tts": false
         });
+        self.apply_allowed_mentions(&mut body);
 
         let body = serde_json::to_string(&body)?;
 
@@ -252,7 +396,79 @@ impl HttpClient {
 
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        let message = res.json()?;
+        self.cache_message(&message);
+
+        Ok(message)
+    }
+
+    /// Creates a new message, and returns the [`Message`]. It's recommended to use [`CreateMessage`]
+    /// builder, it can include a content, an embed, files and a reply reference. This will also trigger
+    /// [`MessageCreate`] event
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`CreateMessage`]: ../../panda/utils/builders/struct.CreateMessage.html
+    /// [`MessageCreate`]: ../../panda/models/gateway/events/struct.MessageCreate.html
+    pub async fn create_message(&self, channel_id: impl AsRef<str>, mut message: CreateMessage) -> Result<Message> {
+        if !message.files.is_empty() {
+            let files = std::mem::take(&mut message.files);
+
+            return self.send_files(channel_id, files, &message).await;
+        }
+
+        let mut body = serde_json::to_value(&message)?;
+        self.apply_allowed_mentions(&mut body);
+
+        let body = serde_json::to_string(&body)?;
+
+        // Create route
+        let route = Route::create_message(channel_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        let message = res.json()?;
+        self.cache_message(&message);
+
+        Ok(message)
+    }
+
+    /// Creates a new message with one or more files, and returns the [`Message`]. `payload` is
+    /// the JSON body of the message, like `{ "content": "...", "embed": ... }`, it's sent as
+    /// `payload_json`. Requires the **ATTACH_FILES** permission. This will also trigger
+    /// [`MessageCreate`] event
+    ///
+    /// ```ignore
+    /// let chart = AttachmentFile::from_path("chart.png").await?;
+    /// http.send_files(channel_id, vec![chart], json!({ "content": "Daily chart" })).await?;
+    /// ```
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`MessageCreate`]: ../../panda/models/gateway/events/struct.MessageCreate.html
+    pub async fn send_files(
+        &self,
+        channel_id: impl AsRef<str>,
+        files: Vec<AttachmentFile>,
+        payload: impl Serialize,
+    ) -> Result<Message> {
+        let mut payload = serde_json::to_value(&payload)?;
+        self.apply_allowed_mentions(&mut payload);
+
+        let mut body = Multipart::new();
+        body.payload_json(&serde_json::to_string(&payload)?);
+
+        for (i, file) in files.iter().enumerate() {
+            body.file(&format!("file{}", i), file);
+        }
+
+        // Create route
+        let route = Route::create_message(channel_id, body);
+
+        let mut res = self._make_multipart_request(route).await?;
+
+        let message = res.json()?;
+        self.cache_message(&message);
+
+        Ok(message)
     }
 
     /// Add a reaction to a [`Message`], it needs the [`Channel`] ID, and [`Message`] ID
@@ -372,6 +588,9 @@ impl HttpClient {
         message_id: impl AsRef<str>,
         body: impl Serialize,
     ) -> Result<Message> {
+        let mut body = serde_json::to_value(&body)?;
+        self.apply_allowed_mentions(&mut body);
+
         let body = serde_json::to_string(&body)?;
 
         let route = Route::edit_message(channel_id, message_id, body);
@@ -410,30 +629,61 @@ impl HttpClient {
 
     /// Edit the channel permission overwrites for a user or role in a channel. Only usable
     /// for guild channels. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`ChannelUpdate`] event.
     ///
-    /// [`Message`]: ../../panda/models/channel/struct.Message.html
-    /// [`MessageDelete`]: ../../panda/models/gateway/events/struct.MessageDelete.html
-    pub async fn edit_channel_permissions(&self, _channel_id: impl AsRef<str>) -> Result<()> {
-        unimplemented!();
-        // // Create RateLimit Key
-        // let route = Route::edit_channel_permissions(channel_id, overwrite_id, body);
+    /// [`ChannelUpdate`]: ../../panda/models/gateway/events/struct.ChannelUpdate.html
+    pub async fn edit_channel_permissions(&self, channel_id: impl AsRef<str>, overwrite: &Overwrite) -> Result<()> {
+        let body = serde_json::to_string(overwrite)?;
+        let route = Route::edit_channel_permissions(channel_id, &overwrite.id, body);
 
-        // let _res = self._make_request(route).await?;
+        let _res = self._make_request(route).await?;
 
-        // Ok(())
+        Ok(())
     }
 
-    pub async fn get_channel_invites(&self, channel_id: impl AsRef<str>) -> Result<()> {
-        let route = Route::get_channel_invite(channel_id);
-        let res = self._make_request(route).await?;
+    /// Delete the channel permission overwrite of a user or role in a channel. Only usable
+    /// for guild channels. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`ChannelUpdate`] event.
+    ///
+    /// [`ChannelUpdate`]: ../../panda/models/gateway/events/struct.ChannelUpdate.html
+    pub async fn delete_channel_permission(
+        &self,
+        channel_id: impl AsRef<str>,
+        overwrite_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let route = Route::delete_channel_permission(channel_id, overwrite_id);
 
-        dbg!(res);
+        let _res = self._make_request(route).await?;
 
         Ok(())
     }
-    // // pub async fn create_channel_invite() {}
 
-    // // pub async fn delete_channel_permissions() {}
+    /// Returns a Vec<[`Invite`]> of the channel, with their metadata. Only usable for guild channels.
+    /// Requires the **MANAGE_CHANNELS** permission.
+    ///
+    /// [`Invite`]: ../../panda/models/invite/struct.Invite.html
+    pub async fn get_channel_invites(&self, channel_id: impl AsRef<str>) -> Result<Vec<Invite>> {
+        let route = Route::get_channel_invite(channel_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Create a new [`Invite`] for the channel. Only usable for guild channels. Requires the
+    /// **CREATE_INSTANT_INVITE** permission. It's recommended to use [`CreateInvite`] builder.
+    /// Fires an [`InviteCreate`] event.
+    ///
+    /// [`Invite`]: ../../panda/models/invite/struct.Invite.html
+    /// [`CreateInvite`]: ../../panda/utils/builders/struct.CreateInvite.html
+    /// [`InviteCreate`]: ../../panda/models/gateway/events/struct.InviteCreate.html
+    pub async fn create_channel_invite(&self, channel_id: impl AsRef<str>, body: impl Serialize) -> Result<Invite> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::create_channel_invite(channel_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
 
     /// Post a typing indicator for the specified channel.
     /// Fires a [`TypingStart`] Gateway event
@@ -485,7 +735,779 @@ impl HttpClient {
         Ok(())
     }
 
-    // PUT/channels/{channel.id}/recipients/{user.id}
+    /// Add a user to a group DM, it needs an OAuth2 access token of the user with the
+    /// `gdm.join` scope
+    pub async fn group_dm_add_recipient(
+        &self,
+        channel_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        access_token: impl AsRef<str>,
+        nick: impl AsRef<str>,
+    ) -> Result<()> {
+        let body = serde_json::json!({
+            "access_token": access_token.as_ref(),
+            "nick": nick.as_ref()
+        });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::group_dm_add_recipient(channel_id, user_id, body);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Remove a user from a group DM
+    pub async fn group_dm_remove_recipient(&self, channel_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::group_dm_remove_recipient(channel_id, user_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * GUILD METHODS
+    // *******************************************************************************
+
+    /// Get a [`Guild`] by ID. The returned guild doesn't contain channels and members.
+    ///
+    /// [`Guild`]: ../../panda/models/guild/struct.Guild.html
+    pub async fn get_guild(&self, guild_id: impl AsRef<str>) -> Result<Guild> {
+        let route = Route::get_guild(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify a guild's settings. Requires the **MANAGE_GUILD** permission. Returns the updated
+    /// [`Guild`] on success. It's recommended to use [`GuildEdit`] builder.
+    /// Fires a [`GuildUpdate`] event.
+    ///
+    /// [`Guild`]: ../../panda/models/guild/struct.Guild.html
+    /// [`GuildEdit`]: ../../panda/utils/builders/struct.GuildEdit.html
+    /// [`GuildUpdate`]: ../../panda/models/gateway/events/struct.GuildUpdate.html
+    pub async fn modify_guild(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<Guild> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a Vec<[`Channel`]> of the guild channels, it doesn't include threads.
+    ///
+    /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
+    pub async fn get_guild_channels(&self, guild_id: impl AsRef<str>) -> Result<Vec<Channel>> {
+        let route = Route::get_guild_channels(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Create a new channel in the guild. Requires the **MANAGE_CHANNELS** permission. Returns the
+    /// new [`Channel`] on success. It's recommended to use [`CreateChannel`] builder.
+    /// Fires a [`ChannelCreate`] event.
+    ///
+    /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
+    /// [`CreateChannel`]: ../../panda/utils/builders/struct.CreateChannel.html
+    /// [`ChannelCreate`]: ../../panda/models/gateway/events/struct.ChannelCreate.html
+    pub async fn create_guild_channel(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<Channel> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::create_guild_channel(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify the positions of the channels of the guild. Requires the **MANAGE_CHANNELS**
+    /// permission. It's recommended to use [`ChannelPositions`] builder.
+    /// Fires multiple [`ChannelUpdate`] events.
+    ///
+    /// [`ChannelPositions`]: ../../panda/utils/builders/struct.ChannelPositions.html
+    /// [`ChannelUpdate`]: ../../panda/models/gateway/events/struct.ChannelUpdate.html
+    pub async fn modify_guild_channel_positions(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<()> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_channel_positions(guild_id, body);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`GuildPreview`] of a public guild, the user doesn't need to be in the guild.
+    ///
+    /// [`GuildPreview`]: ../../panda/models/guild/struct.GuildPreview.html
+    pub async fn get_guild_preview(&self, guild_id: impl AsRef<str>) -> Result<GuildPreview> {
+        let route = Route::get_guild_preview(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns the number of members that would be removed in a prune operation.
+    /// Requires the **KICK_MEMBERS** permission.
+    pub async fn get_guild_prune_count(&self, guild_id: impl AsRef<str>, prune: GuildPrune) -> Result<u64> {
+        let route = Route::get_guild_prune_count(guild_id, &prune.query());
+        let mut res = self._make_request(route).await?;
+
+        let count: PruneCount<u64> = res.json()?;
+
+        Ok(count.pruned)
+    }
+
+    /// Begin a prune operation, it kicks the inactive members. Requires the **KICK_MEMBERS**
+    /// permission. Returns the number of removed members if `compute_prune_count` is enabled
+    /// in the [`GuildPrune`]. Fires multiple [`GuildMemberRemove`] events.
+    ///
+    /// [`GuildPrune`]: ../../panda/utils/builders/struct.GuildPrune.html
+    /// [`GuildMemberRemove`]: ../../panda/models/gateway/events/struct.GuildMemberRemove.html
+    pub async fn begin_guild_prune(&self, guild_id: impl AsRef<str>, prune: GuildPrune) -> Result<Option<u64>> {
+        let route = Route::begin_guild_prune(guild_id, &prune.query());
+        let mut res = self._make_request(route).await?;
+
+        let count: PruneCount<Option<u64>> = res.json()?;
+
+        Ok(count.pruned)
+    }
+
+    /// Returns a Vec<[`VoiceRegion`]> for the guild, it includes the VIP regions if the guild
+    /// has the `VIP_REGIONS` feature.
+    ///
+    /// [`VoiceRegion`]: ../../panda/models/voice/struct.VoiceRegion.html
+    pub async fn get_guild_voice_regions(&self, guild_id: impl AsRef<str>) -> Result<Vec<VoiceRegion>> {
+        let route = Route::get_guild_voice_regions(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns the [`VanityUrl`] of the guild. Requires the **MANAGE_GUILD** permission.
+    ///
+    /// [`VanityUrl`]: ../../panda/models/guild/struct.VanityUrl.html
+    pub async fn get_guild_vanity_url(&self, guild_id: impl AsRef<str>) -> Result<VanityUrl> {
+        let route = Route::get_guild_vanity_url(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns the [`GuildWidget`] settings of the guild. Requires the **MANAGE_GUILD** permission.
+    ///
+    /// [`GuildWidget`]: ../../panda/models/guild/struct.GuildWidget.html
+    pub async fn get_guild_widget(&self, guild_id: impl AsRef<str>) -> Result<GuildWidget> {
+        let route = Route::get_guild_widget(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify the widget settings of the guild. Requires the **MANAGE_GUILD** permission. Returns
+    /// the updated [`GuildWidget`]. It's recommended to use [`GuildWidgetEdit`] builder.
+    ///
+    /// [`GuildWidget`]: ../../panda/models/guild/struct.GuildWidget.html
+    /// [`GuildWidgetEdit`]: ../../panda/utils/builders/struct.GuildWidgetEdit.html
+    pub async fn modify_guild_widget(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<GuildWidget> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_widget(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    // *******************************************************************************
+    // * GUILD MEMBER METHODS
+    // *******************************************************************************
+
+    /// Returns the [`GuildMember`] of the user in the guild
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    pub async fn get_guild_member(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<GuildMember> {
+        let route = Route::get_guild_member(guild_id, user_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a Vec<[`GuildMember`]> of the guild, sorted by user ID. It returns up to `limit`
+    /// members (1 - 1000) with user ID greater than `after`, use "0" to get the first page.
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    pub async fn list_guild_members(
+        &self,
+        guild_id: impl AsRef<str>,
+        after: impl AsRef<str>,
+        limit: u16,
+    ) -> Result<Vec<GuildMember>> {
+        let route = Route::list_guild_members(guild_id, after, limit);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a Vec<[`GuildMember`]> whose username or nickname starts with the query,
+    /// up to `limit` members (1 - 1000)
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    pub async fn search_guild_members(
+        &self,
+        guild_id: impl AsRef<str>,
+        query: impl AsRef<str>,
+        limit: u16,
+    ) -> Result<Vec<GuildMember>> {
+        let query = Serializer::new(String::new())
+            .append_pair("query", query.as_ref())
+            .append_pair("limit", &limit.to_string())
+            .finish();
+
+        let route = Route::search_guild_members(guild_id, &query);
+        let mut res = self._make_request(route).await?;
 
-    // DELETE/channels/{channel.id}/recipients/{user.id}
+        Ok(res.json()?)
+    }
+
+    /// Modify attributes of a guild member. It's recommended to use [`MemberEdit`] builder, each
+    /// attribute requires a different permission. Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`MemberEdit`]: ../../panda/utils/builders/struct.MemberEdit.html
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn modify_guild_member(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        body: impl Serialize,
+    ) -> Result<()> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_member(guild_id, user_id, body);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Modify the nickname of the current user in a guild, an empty string removes it.
+    /// Requires the **CHANGE_NICKNAME** permission. Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn modify_current_user_nick(&self, guild_id: impl AsRef<str>, nick: impl AsRef<str>) -> Result<()> {
+        let body = serde_json::json!({ "nick": nick.as_ref() });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::modify_current_user_nick(guild_id, body);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Add a role to a guild member. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn add_guild_member_role(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let route = Route::add_guild_member_role(guild_id, user_id, role_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Remove a role from a guild member. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn remove_guild_member_role(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let route = Route::remove_guild_member_role(guild_id, user_id, role_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Remove a member from the guild (kick). Requires the **KICK_MEMBERS** permission.
+    /// Fires a [`GuildMemberRemove`] event.
+    ///
+    /// [`GuildMemberRemove`]: ../../panda/models/gateway/events/struct.GuildMemberRemove.html
+    pub async fn kick_member(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::remove_guild_member(guild_id, user_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * GUILD BAN METHODS
+    // *******************************************************************************
+
+    /// Returns a Vec<[`Ban`]> of the guild. Requires the **BAN_MEMBERS** permission.
+    ///
+    /// [`Ban`]: ../../panda/models/guild/struct.Ban.html
+    pub async fn get_bans(&self, guild_id: impl AsRef<str>) -> Result<Vec<Ban>> {
+        let route = Route::get_guild_bans(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns the [`Ban`] of the user. Requires the **BAN_MEMBERS** permission.
+    ///
+    /// [`Ban`]: ../../panda/models/guild/struct.Ban.html
+    pub async fn get_ban(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<Ban> {
+        let route = Route::get_guild_ban(guild_id, user_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Ban an user from the guild, and delete its messages of the last `delete_message_days`
+    /// (0 - 7). Requires the **BAN_MEMBERS** permission. Fires a [`GuildBanAdd`] event.
+    ///
+    /// The reason of the ban is set with [`reason`], like the other requests.
+    ///
+    /// ```ignore
+    /// http.reason("Spam").ban_member(guild_id, user_id, 1).await?;
+    /// ```
+    ///
+    /// [`GuildBanAdd`]: ../../panda/models/gateway/events/struct.GuildBanAdd.html
+    /// [`reason`]: #method.reason
+    pub async fn ban_member(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        delete_message_days: u8,
+    ) -> Result<()> {
+        let mut query = Serializer::new(String::new());
+        query.append_pair("delete-message-days", &delete_message_days.to_string());
+
+        // Discord takes the reason of the ban from the query, not from the audit log header
+        if let Some(reason) = &self.reason {
+            query.append_pair("reason", reason);
+        }
+
+        let route = Route::create_guild_ban(guild_id, user_id, &query.finish());
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Ban a list of users one by one, waiting when the rate limit is reached. It doesn't stop
+    /// when a ban fails, instead it returns the result of each user.
+    /// Requires the **BAN_MEMBERS** permission. Fires a [`GuildBanAdd`] event for each user.
+    ///
+    /// [`GuildBanAdd`]: ../../panda/models/gateway/events/struct.GuildBanAdd.html
+    pub async fn ban_members<I, T>(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_ids: I,
+        delete_message_days: u8,
+    ) -> Vec<(String, Result<()>)>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let mut results = Vec::new();
+
+        for user_id in user_ids {
+            let user_id = user_id.into();
+            let result = self.ban_member(guild_id.as_ref(), &user_id, delete_message_days).await;
+
+            results.push((user_id, result));
+        }
+
+        results
+    }
+
+    /// Remove the ban of an user. Requires the **BAN_MEMBERS** permission.
+    /// Fires a [`GuildBanRemove`] event.
+    ///
+    /// [`GuildBanRemove`]: ../../panda/models/gateway/events/struct.GuildBanRemove.html
+    pub async fn unban_member(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::remove_guild_ban(guild_id, user_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * GUILD ROLE METHODS
+    // *******************************************************************************
+
+    /// Returns a Vec<[`Role`]> of the guild
+    ///
+    /// [`Role`]: ../../panda/models/guild/struct.Role.html
+    pub async fn get_roles(&self, guild_id: impl AsRef<str>) -> Result<Vec<Role>> {
+        let route = Route::get_guild_roles(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Create a new role in the guild. Requires the **MANAGE_ROLES** permission. Returns the new
+    /// [`Role`] on success. It's recommended to use [`RoleEdit`] builder.
+    /// Fires a [`GuildRoleCreate`] event.
+    ///
+    /// [`Role`]: ../../panda/models/guild/struct.Role.html
+    /// [`RoleEdit`]: ../../panda/utils/builders/struct.RoleEdit.html
+    /// [`GuildRoleCreate`]: ../../panda/models/gateway/events/struct.GuildRoleCreate.html
+    pub async fn create_role(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<Role> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::create_guild_role(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify a role of the guild. Requires the **MANAGE_ROLES** permission. Returns the updated
+    /// [`Role`] on success. It's recommended to use [`RoleEdit`] builder.
+    /// Fires a [`GuildRoleUpdate`] event.
+    ///
+    /// [`Role`]: ../../panda/models/guild/struct.Role.html
+    /// [`RoleEdit`]: ../../panda/utils/builders/struct.RoleEdit.html
+    /// [`GuildRoleUpdate`]: ../../panda/models/gateway/events/struct.GuildRoleUpdate.html
+    pub async fn modify_role(
+        &self,
+        guild_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        body: impl Serialize,
+    ) -> Result<Role> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_role(guild_id, role_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify the positions of the roles of the guild. Requires the **MANAGE_ROLES** permission.
+    /// Returns all roles of the guild. It's recommended to use [`RolePositions`] builder.
+    /// Fires multiple [`GuildRoleUpdate`] events.
+    ///
+    /// [`RolePositions`]: ../../panda/utils/builders/struct.RolePositions.html
+    /// [`GuildRoleUpdate`]: ../../panda/models/gateway/events/struct.GuildRoleUpdate.html
+    pub async fn modify_role_positions(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<Vec<Role>> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_role_positions(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Delete a role of the guild. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`GuildRoleDelete`] event.
+    ///
+    /// [`GuildRoleDelete`]: ../../panda/models/gateway/events/struct.GuildRoleDelete.html
+    pub async fn delete_role(&self, guild_id: impl AsRef<str>, role_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::delete_guild_role(guild_id, role_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * INVITE METHODS
+    // *******************************************************************************
+
+    /// Returns an [`Invite`] by its code, with the approximate member and presence counts
+    ///
+    /// [`Invite`]: ../../panda/models/invite/struct.Invite.html
+    pub async fn get_invite(&self, invite_code: impl AsRef<str>) -> Result<Invite> {
+        let route = Route::get_invite(invite_code);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Delete an invite. Requires the **MANAGE_CHANNELS** permission on the channel of the
+    /// invite, or **MANAGE_GUILD** to remove any invite of the guild. Returns the deleted [`Invite`].
+    /// Fires an [`InviteDelete`] event.
+    ///
+    /// [`Invite`]: ../../panda/models/invite/struct.Invite.html
+    /// [`InviteDelete`]: ../../panda/models/gateway/events/struct.InviteDelete.html
+    pub async fn delete_invite(&self, invite_code: impl AsRef<str>) -> Result<Invite> {
+        let route = Route::delete_invite(invite_code);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    // *******************************************************************************
+    // * USER METHODS
+    // *******************************************************************************
+
+    /// Returns the [`User`] of the client
+    ///
+    /// [`User`]: ../../panda/models/user/struct.User.html
+    pub async fn get_current_user(&self) -> Result<User> {
+        let route = Route::get_current_user();
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify the username or avatar of the client, returns the updated [`User`] on success.
+    /// It's recommended to use [`UserEdit`] builder. Fires a [`UserUpdate`] event.
+    ///
+    /// [`User`]: ../../panda/models/user/struct.User.html
+    /// [`UserEdit`]: ../../panda/utils/builders/struct.UserEdit.html
+    /// [`UserUpdate`]: ../../panda/models/gateway/events/struct.UserUpdate.html
+    pub async fn modify_current_user(&self, body: impl Serialize) -> Result<User> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_current_user(body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a Vec<[`PartialGuild`]> of the guilds of the client, sorted by guild ID. It returns
+    /// up to `limit` guilds (1 - 100) with ID greater than `after`, use "0" to get the first page.
+    ///
+    /// [`PartialGuild`]: ../../panda/models/guild/struct.PartialGuild.html
+    pub async fn get_current_user_guilds(&self, after: impl AsRef<str>, limit: u8) -> Result<Vec<PartialGuild>> {
+        let route = Route::get_current_user_guilds(after, limit);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Leave a guild. Fires a [`GuildDelete`] event.
+    ///
+    /// [`GuildDelete`]: ../../panda/models/gateway/events/struct.GuildDelete.html
+    pub async fn leave_guild(&self, guild_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::leave_guild(guild_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Open a DM with a user, returns the DM [`Channel`]. If the DM already exists the same channel
+    /// is returned.
+    ///
+    /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
+    pub async fn create_dm(&self, recipient_id: impl AsRef<str>) -> Result<Channel> {
+        let body = serde_json::json!({ "recipient_id": recipient_id.as_ref() });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::create_dm(body);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a [`User`] by ID
+    ///
+    /// [`User`]: ../../panda/models/user/struct.User.html
+    pub async fn get_user(&self, user_id: impl AsRef<str>) -> Result<User> {
+        let route = Route::get_user(user_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    // *******************************************************************************
+    // * EMOJI METHODS
+    // *******************************************************************************
+
+    /// Returns a Vec<[`Emoji`]> of the guild
+    ///
+    /// [`Emoji`]: ../../panda/models/emoji/struct.Emoji.html
+    pub async fn get_guild_emojis(&self, guild_id: impl AsRef<str>) -> Result<Vec<Emoji>> {
+        let route = Route::list_guild_emojis(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns an [`Emoji`] of the guild by ID
+    ///
+    /// [`Emoji`]: ../../panda/models/emoji/struct.Emoji.html
+    pub async fn get_guild_emoji(&self, guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>) -> Result<Emoji> {
+        let route = Route::get_guild_emoji(guild_id, emoji_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Create a new emoji in the guild. Requires the **MANAGE_EMOJIS** permission. `image` is the
+    /// content of a PNG, JPEG or GIF image (max 256kb), if `roles` is not empty only members with
+    /// one of these roles can use the emoji. Returns the new [`Emoji`] on success.
+    /// Fires a [`GuildEmojisUpdate`] event.
+    ///
+    /// [`Emoji`]: ../../panda/models/emoji/struct.Emoji.html
+    /// [`GuildEmojisUpdate`]: ../../panda/models/gateway/events/struct.GuildEmojisUpdate.html
+    pub async fn create_guild_emoji(
+        &self,
+        guild_id: impl AsRef<str>,
+        name: impl AsRef<str>,
+        image: impl AsRef<[u8]>,
+        roles: &[String],
+    ) -> Result<Emoji> {
+        let body = serde_json::json!({
+            "name": name.as_ref(),
+            "image": image_data_uri(image)?,
+            "roles": roles
+        });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::create_guild_emoji(guild_id, body);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify the name or the roles of an emoji of the guild. Requires the **MANAGE_EMOJIS**
+    /// permission. Returns the updated [`Emoji`] on success. It's recommended to use
+    /// [`EmojiEdit`] builder. Fires a [`GuildEmojisUpdate`] event.
+    ///
+    /// [`Emoji`]: ../../panda/models/emoji/struct.Emoji.html
+    /// [`EmojiEdit`]: ../../panda/utils/builders/struct.EmojiEdit.html
+    /// [`GuildEmojisUpdate`]: ../../panda/models/gateway/events/struct.GuildEmojisUpdate.html
+    pub async fn modify_guild_emoji(
+        &self,
+        guild_id: impl AsRef<str>,
+        emoji_id: impl AsRef<str>,
+        body: impl Serialize,
+    ) -> Result<Emoji> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_emoji(guild_id, emoji_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Delete an emoji of the guild. Requires the **MANAGE_EMOJIS** permission.
+    /// Fires a [`GuildEmojisUpdate`] event.
+    ///
+    /// [`GuildEmojisUpdate`]: ../../panda/models/gateway/events/struct.GuildEmojisUpdate.html
+    pub async fn delete_guild_emoji(&self, guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::delete_guild_emoji(guild_id, emoji_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * WEBHOOK METHODS
+    // *******************************************************************************
+
+    /// Create a new webhook in the channel. Requires the **MANAGE_WEBHOOKS** permission. `avatar`
+    /// must be an image encoded as a data URI, see [`image_data_uri`]. Returns the new [`Webhook`]
+    /// on success, with its token. Fires a [`WebhooksUpdate`] event.
+    ///
+    /// [`image_data_uri`]: ../../panda/utils/fn.image_data_uri.html
+    /// [`Webhook`]: ../../panda/models/webhook/struct.Webhook.html
+    /// [`WebhooksUpdate`]: ../../panda/models/gateway/events/struct.WebhooksUpdate.html
+    pub async fn create_webhook(
+        &self,
+        channel_id: impl AsRef<str>,
+        name: impl AsRef<str>,
+        avatar: Option<&str>,
+    ) -> Result<Webhook> {
+        let body = serde_json::json!({
+            "name": name.as_ref(),
+            "avatar": avatar
+        });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::create_webhook(channel_id, body);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a Vec<[`Webhook`]> of the channel. Requires the **MANAGE_WEBHOOKS** permission.
+    ///
+    /// [`Webhook`]: ../../panda/models/webhook/struct.Webhook.html
+    pub async fn get_channel_webhooks(&self, channel_id: impl AsRef<str>) -> Result<Vec<Webhook>> {
+        let route = Route::get_channel_webhooks(channel_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a Vec<[`Webhook`]> of the guild. Requires the **MANAGE_WEBHOOKS** permission.
+    ///
+    /// [`Webhook`]: ../../panda/models/webhook/struct.Webhook.html
+    pub async fn get_guild_webhooks(&self, guild_id: impl AsRef<str>) -> Result<Vec<Webhook>> {
+        let route = Route::get_guild_webhooks(guild_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Returns a [`Webhook`] by ID
+    ///
+    /// [`Webhook`]: ../../panda/models/webhook/struct.Webhook.html
+    pub async fn get_webhook(&self, webhook_id: impl AsRef<str>) -> Result<Webhook> {
+        let route = Route::get_webhook(webhook_id);
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Modify a webhook. Requires the **MANAGE_WEBHOOKS** permission. Returns the updated
+    /// [`Webhook`] on success. It's recommended to use [`WebhookEdit`] builder.
+    /// Fires a [`WebhooksUpdate`] event.
+    ///
+    /// [`Webhook`]: ../../panda/models/webhook/struct.Webhook.html
+    /// [`WebhookEdit`]: ../../panda/utils/builders/struct.WebhookEdit.html
+    /// [`WebhooksUpdate`]: ../../panda/models/gateway/events/struct.WebhooksUpdate.html
+    pub async fn modify_webhook(&self, webhook_id: impl AsRef<str>, body: impl Serialize) -> Result<Webhook> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_webhook(webhook_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Delete a webhook. Requires the **MANAGE_WEBHOOKS** permission.
+    /// Fires a [`WebhooksUpdate`] event.
+    ///
+    /// [`WebhooksUpdate`]: ../../panda/models/gateway/events/struct.WebhooksUpdate.html
+    pub async fn delete_webhook(&self, webhook_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::delete_webhook(webhook_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * AUDIT LOG METHODS
+    // *******************************************************************************
+
+    /// Returns an [`AuditLog`] of the guild, with the entries that match the [`AuditLogFilter`].
+    /// Requires the **VIEW_AUDIT_LOG** permission.
+    ///
+    /// [`AuditLog`]: ../../panda/models/guild/struct.AuditLog.html
+    /// [`AuditLogFilter`]: ../../panda/utils/builders/struct.AuditLogFilter.html
+    pub async fn get_audit_log(&self, guild_id: impl AsRef<str>, filter: AuditLogFilter) -> Result<AuditLog> {
+        let route = Route::get_guild_audit_log(guild_id, &filter.query());
+        let mut res = self._make_request(route).await?;
+
+        Ok(res.json()?)
+    }
 }
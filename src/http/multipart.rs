@@ -0,0 +1,165 @@
+use crate::{error::Result, runtime};
+
+use isahc::Body;
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A file to upload with a message, the filename is shown in Discord and its extension is used
+/// to display images and videos
+#[derive(Clone, Debug)]
+pub struct AttachmentFile {
+    filename: String,
+    data: Vec<u8>,
+}
+
+impl AttachmentFile {
+    /// Creates a new file with its content
+    pub fn new(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> AttachmentFile {
+        AttachmentFile {
+            filename: filename.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Read a file asynchronously, the filename is the last component of the path
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<AttachmentFile> {
+        let path = path.as_ref();
+        let data = runtime::read_file(path).await?;
+        let filename = path
+            .file_name()
+            .map(|f| f.to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("file"));
+
+        Ok(AttachmentFile { filename, data })
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// A multipart/form-data body, used to upload files
+pub(crate) struct Multipart {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Multipart {
+    pub(crate) fn new() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+
+        Multipart {
+            boundary: format!("panda-boundary-{:x}", nanos),
+            body: Vec::new(),
+        }
+    }
+
+    /// Add the JSON body of the request, like the content and the embed of a message
+    pub(crate) fn payload_json(&mut self, json: &str) {
+        self.start_part("name=\"payload_json\"", "application/json");
+        self.body.extend_from_slice(json.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+    }
+
+    /// Add a file, each file needs a different name
+    pub(crate) fn file(&mut self, name: &str, file: &AttachmentFile) {
+        // Quotes and line breaks would break the header
+        let filename: String = file
+            .filename
+            .chars()
+            .map(|c| match c {
+                '"' | '\r' | '\n' => '_',
+                c => c,
+            })
+            .collect();
+
+        let disposition = format!("name=\"{}\"; filename=\"{}\"", name, filename);
+        self.start_part(&disposition, "application/octet-stream");
+        self.body.extend_from_slice(&file.data);
+        self.body.extend_from_slice(b"\r\n");
+    }
+
+    pub(crate) fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    fn start_part(&mut self, disposition: &str, content_type: &str) {
+        let header = format!(
+            "--{}\r\nContent-Disposition: form-data; {}\r\nContent-Type: {}\r\n\r\n",
+            self.boundary, disposition, content_type
+        );
+        self.body.extend_from_slice(header.as_bytes());
+    }
+}
+
+impl From<Multipart> for Body {
+    fn from(mut multipart: Multipart) -> Body {
+        let end = format!("--{}--\r\n", multipart.boundary);
+        multipart.body.extend_from_slice(end.as_bytes());
+
+        Body::from(multipart.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::io::AsyncReadExt;
+
+    async fn body_string(multipart: Multipart) -> String {
+        let mut body = Vec::new();
+        Body::from(multipart).read_to_end(&mut body).await.unwrap();
+
+        String::from_utf8(body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn body_layout() {
+        let mut multipart = Multipart::new();
+        let boundary = multipart.boundary.clone();
+        assert_eq!(
+            multipart.content_type(),
+            format!("multipart/form-data; boundary={}", boundary)
+        );
+
+        multipart.payload_json(r#"{"content":"hi"}"#);
+        multipart.file("file0", &AttachmentFile::new("a.txt", "first"));
+        multipart.file("file1", &AttachmentFile::new("b.png", "second"));
+
+        let expected = format!(
+            "--{b}\r\n\
+             Content-Disposition: form-data; name=\"payload_json\"\r\n\
+             Content-Type: application/json\r\n\r\n\
+             {{\"content\":\"hi\"}}\r\n\
+             --{b}\r\n\
+             Content-Disposition: form-data; name=\"file0\"; filename=\"a.txt\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n\
+             first\r\n\
+             --{b}\r\n\
+             Content-Disposition: form-data; name=\"file1\"; filename=\"b.png\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n\
+             second\r\n\
+             --{b}--\r\n",
+            b = boundary
+        );
+        assert_eq!(body_string(multipart).await, expected);
+    }
+
+    #[tokio::test]
+    async fn filename_cant_break_the_header() {
+        let mut multipart = Multipart::new();
+        multipart.file("file0", &AttachmentFile::new("a\"b\r\nc.txt", "data"));
+
+        let body = body_string(multipart).await;
+        assert!(body.contains("name=\"file0\"; filename=\"a_b__c.txt\"\r\n"));
+    }
+}
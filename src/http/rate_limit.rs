@@ -3,76 +3,207 @@ use std::{
     collections::HashMap,
     default::Default,
     sync::Arc,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant},
 };
 
 use futures::lock::Mutex;
 
-use isahc::{http::Response, Body};
+use isahc::{
+    http::{HeaderMap, Method, Response, StatusCode},
+    Body,
+};
 
-#[derive(Default)]
+/// Max number of times a request is sent again after a 429 response
+pub(crate) const MAX_RETRIES: u32 = 3;
+
+// Used when a 429 response doesn't say how long to wait
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Rate limits of the Discord API. Discord groups the routes in buckets, identified by the
+/// `X-RateLimit-Bucket` hash of the responses, each bucket has a limit per major parameter
+/// (channel, guild or webhook). There is also a global limit, that affects all routes.
+///
+/// Clones share the same limits.
+#[derive(Clone, Default)]
 pub(crate) struct RateLimit {
-    // Key needs to be {major_parameter}:{channel_id/guild_id}
-    // Example:
-    // - "channels:639562328521703445"
-    // - "channels/messages:639562328521703445"
-    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+    state: Arc<Mutex<State>>,
 }
 
 #[derive(Default)]
+struct State {
+    // Route key -> bucket hash, the routes are known after their first response
+    hashes: HashMap<String, String>,
+    // "{bucket hash}:{major parameter}" -> Bucket, the route key is used until the hash is known
+    buckets: HashMap<String, Bucket>,
+    // When the global rate limit ends
+    global_reset: Option<Instant>,
+}
+
 struct Bucket {
-    pub limit: u32,
-    pub remaining: u32,
-    pub reset: u64,
+    limit: u32,
+    remaining: u32,
+    reset: Instant,
 }
 
 impl RateLimit {
-    pub(crate) async fn check_and_sleep(&self, bucket_key: &str) {
-        // Get bucket from key
-        let mut buckets_hm = self.buckets.lock().await;
-        let bucket = buckets_hm.get_mut(bucket_key);
-
-        // If it exists, check
-        // If not exists, we assume that it's safe to make the api call
-        if let Some(b) = bucket {
-            // Get current time
-            let current = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-            // Sleep reset time
-            let difference = Duration::from_secs(b.reset.saturating_sub(current));
-
-            if difference.as_secs() == 0 {
-                // We assume that we have waited the reset time
-                // but the reset value have not been updated
-                b.remaining += 1;
-            } else if b.remaining == 0 {
-                runtime::sleep(difference).await;
-
-                // Add the default remaining
-                b.remaining = b.limit;
+    /// Wait until the route can be requested without reaching a rate limit, and reserve one
+    /// request of its bucket.
+    ///
+    /// `route_key` identifies the route (see [`route_key`]) and `major` is its major parameter,
+    /// like `"channels:639562328521703445"`.
+    pub(crate) async fn acquire(&self, route_key: &str, major: &str) {
+        loop {
+            // The lock must not be held while sleeping, other routes can be requested meanwhile
+            let wait = self.state.lock().await.reserve(route_key, major, Instant::now());
+
+            match wait {
+                Some(wait) => runtime::sleep(wait).await,
+                None => return,
+            }
+        }
+    }
+
+    /// Update the limits with the headers of the response. If it's a 429 response, returns how long
+    /// to wait before retrying the request.
+    pub(crate) async fn update(&self, route_key: &str, major: &str, response: &Response<Body>) -> Option<Duration> {
+        self.state
+            .lock()
+            .await
+            .update(route_key, major, response.status(), response.headers(), Instant::now())
+    }
+}
+
+impl State {
+    fn bucket_id(&self, route_key: &str, major: &str) -> String {
+        let hash = self.hashes.get(route_key).map(String::as_str).unwrap_or(route_key);
+
+        format!("{}:{}", hash, major)
+    }
+
+    /// Returns how long to wait if the route can't be requested now
+    fn reserve(&mut self, route_key: &str, major: &str, now: Instant) -> Option<Duration> {
+        if let Some(reset) = self.global_reset {
+            if reset > now {
+                return Some(reset - now);
             }
-            // If passes all then it's safe to make the api call
 
-            b.remaining -= 1;
+            self.global_reset = None;
         }
+
+        let id = self.bucket_id(route_key, major);
+
+        if let Some(bucket) = self.buckets.get_mut(&id) {
+            if bucket.remaining == 0 {
+                if bucket.reset > now {
+                    return Some(bucket.reset - now);
+                }
+
+                // The bucket was reset, the next response will update it
+                bucket.remaining = bucket.limit;
+            }
+
+            bucket.remaining = bucket.remaining.saturating_sub(1);
+        }
+
+        // If the bucket is unknown, we assume that it's safe to make the api call
+        None
     }
 
-    pub(crate) async fn update(&self, bucket_key: String, response: &Response<Body>) {
-        let headers = response.headers();
-        let mut buckets_hm = self.buckets.lock().await;
+    fn update(
+        &mut self,
+        route_key: &str,
+        major: &str,
+        status: StatusCode,
+        headers: &HeaderMap,
+        now: Instant,
+    ) -> Option<Duration> {
+        if let Some(hash) = header::<String>(headers, "x-ratelimit-bucket") {
+            self.hashes.insert(route_key.to_string(), hash);
+        }
+
+        let id = self.bucket_id(route_key, major);
+
+        let limit = header::<u32>(headers, "x-ratelimit-limit");
+        let remaining = header::<u32>(headers, "x-ratelimit-remaining");
+        let reset_after = header::<f64>(headers, "x-ratelimit-reset-after").and_then(duration_from_secs);
 
-        // Get the bucket and update all entries
-        let bucket = buckets_hm.entry(bucket_key).or_default();
+        if let (Some(limit), Some(remaining), Some(reset_after)) = (limit, remaining, reset_after) {
+            let bucket = Bucket {
+                limit,
+                remaining,
+                reset: now + reset_after,
+            };
 
-        if let Some(limit) = headers.get("x-ratelimit-limit") {
-            bucket.limit = limit.to_str().unwrap().parse::<u32>().unwrap();
+            self.buckets.insert(id.clone(), bucket);
         }
 
-        if let Some(remaining) = headers.get("x-ratelimit-remaining") {
-            bucket.remaining = remaining.to_str().unwrap().parse::<u32>().unwrap();
+        if status != StatusCode::TOO_MANY_REQUESTS {
+            return None;
         }
 
-        if let Some(reset) = headers.get("x-ratelimit-reset") {
-            bucket.reset = reset.to_str().unwrap().parse::<u64>().unwrap();
+        let is_global = header::<bool>(headers, "x-ratelimit-global").unwrap_or(false);
+        // The Retry-After header is in seconds, only the retry_after of the body is in milliseconds
+        let retry_after = header::<f64>(headers, "retry-after").and_then(duration_from_secs);
+
+        if is_global {
+            let wait = retry_after.unwrap_or(DEFAULT_RETRY_AFTER);
+            self.global_reset = Some(now + wait);
+
+            return Some(wait);
         }
+
+        let wait = reset_after.or(retry_after).unwrap_or(DEFAULT_RETRY_AFTER);
+
+        // Nothing can be requested in this bucket until the wait ends
+        if let Some(bucket) = self.buckets.get_mut(&id) {
+            bucket.remaining = 0;
+            bucket.reset = now + wait;
+        }
+
+        Some(wait)
+    }
+}
+
+/// Returns the key that identifies a route, it's the method and the path without IDs,
+/// like `"POST /channels/{id}/messages"`, the webhook tokens are also removed. Routes with the same key
+/// always have the same bucket.
+pub(crate) fn route_key(method: &Method, uri: &str) -> String {
+    // Remove the scheme and the host, if there is one, and the query
+    let path = match uri.find("://") {
+        Some(i) => uri[i + 3..].find('/').map(|j| &uri[i + 3 + j..]).unwrap_or("/"),
+        None => uri,
+    };
+    let path = path.split('?').next().unwrap_or_default();
+
+    let mut previous = ["", ""];
+    let path: Vec<&str> = path
+        .split('/')
+        .map(|segment| {
+            let key = if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else if previous == ["webhooks", "{id}"] {
+                // The webhook token is a secret, it must not appear in the logs or in the errors
+                "{token}"
+            } else {
+                segment
+            };
+
+            previous = [previous[1], key];
+            key
+        })
+        .collect();
+
+    format!("{} {}", method, path.join("/"))
+}
+
+fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn duration_from_secs(secs: f64) -> Option<Duration> {
+    if secs.is_finite() && secs >= 0.0 {
+        Some(Duration::from_secs_f64(secs))
+    } else {
+        None
     }
 }
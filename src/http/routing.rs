@@ -4,17 +4,32 @@ use isahc::{http::Method, Body};
 
 macro_rules! bucket_key {
     (channel: $id: expr) => {
-        format!("channels:{}", $id.as_ref());
+        format!("channels:{}", $id.as_ref())
     };
     (guild: $id: expr) => {
-        format!("guild:{}", $id.as_ref());
+        format!("guild:{}", $id.as_ref())
     };
     (emoji: $id: expr) => {
-        format!("emoji:{}", $id.as_ref());
+        format!("emoji:{}", $id.as_ref())
+    };
+    (invite: $code: expr) => {
+        format!("invite:{}", $code.as_ref())
+    };
+    (user: $id: expr) => {
+        format!("user:{}", $id.as_ref())
+    };
+    (webhook: $id: expr) => {
+        format!("webhook:{}", $id.as_ref())
+    };
+    (current_user) => {
+        String::from("user:@me")
     };
 }
 
 macro_rules! api_request {
+    ($url: expr) => {
+        concat!("https://discord.com/api/v6", $url).to_string()
+    };
     ($url: expr, $($rest: expr),*) => {
         format!(concat!("https://discord.com/api/v6", $url), $($rest),*)
     };
@@ -322,134 +337,962 @@ impl Route<()> {
             body: (),
         }
     }
-}
 
-// Routes with body
-impl<B: Into<Body>> Route<B> {
-    // pub(crate) fn as_request(self, token: &str) -> (String, Request<Body>) {
-    //     // let request = match self.method {
-    //     //     Method::GET | Method::PUT | Method::DELETE => Request::builder()
-    //     //         .method(&self.method)
-    //     //         .uri(&self.uri)
-    //     //         .header("Authorization", token)
-    //     //         .body(())
-    //     //         .unwrap(),
-    //     //     Method::POST | Method::PATCH => Request::builder()
-    //     //         .method(&self.method)
-    //     //         .uri(&self.uri)
-    //     //         .header("Authorization", token)
-    //     //         .header("Content-Type", "application/json")
-    //     //         .body(self.body.into())
-    //     //         .unwrap(),
-    //     //     _ => unimplemented!(),
-    //     // };
+    // GET/guilds/{guild.id}
+    pub(crate) fn get_guild(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
 
-    //     let request = match self.body {
-    //         () => Request::builder()
-    //             .method(&self.method)
-    //             .uri(&self.uri)
-    //             .header("Authorization", token)
-    //             .body(())
-    //             .unwrap(),
-    //         _ => Request::builder()
-    //             .method(&self.method)
-    //             .uri(&self.uri)
-    //             .header("Authorization", token)
-    //             .header("Content-Type", "application/json")
-    //             .body(self.body)
-    //             .unwrap(),
-    //     };
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
 
-    //     println!("{:?}", request);
+    // GET/guilds/{guild.id}/channels
+    pub(crate) fn get_guild_channels(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/channels", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
 
-    //     (self.bucket_key, request)
-    // }
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
 
-    // PATCH/channels/{channel.id}
-    pub(crate) fn edit_channel(channel_id: impl AsRef<str>, body: B) -> Self {
-        let method = Method::PATCH;
-        let uri = api_request!("/channels/{}", channel_id.as_ref());
-        let bucket_key = bucket_key!(channel: channel_id);
+    // GET/guilds/{guild.id}/preview
+    pub(crate) fn get_guild_preview(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/preview", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
 
-        Self {
+        Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // POST/channels/{channel.id}/messages
-    pub(crate) fn create_message(channel_id: impl AsRef<str>, body: B) -> Self {
+    // GET/guilds/{guild.id}/prune
+    pub(crate) fn get_guild_prune_count(guild_id: impl AsRef<str>, query: &str) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/prune?{}", guild_id.as_ref(), query);
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // POST/guilds/{guild.id}/prune
+    pub(crate) fn begin_guild_prune(guild_id: impl AsRef<str>, query: &str) -> Self {
         let method = Method::POST;
-        let uri = api_request!("/channels/{}/messages", channel_id.as_ref());
-        let bucket_key = bucket_key!(channel: channel_id);
+        let uri = api_request!("/guilds/{}/prune?{}", guild_id.as_ref(), query);
+        let bucket_key = bucket_key!(guild: guild_id);
 
-        Self {
+        Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // PATCH/channels/{channel.id}/messages/{message.id}
-    pub(crate) fn edit_message(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>, body: B) -> Self {
-        let method = Method::PATCH;
-        let uri = api_request!("/channels/{}/messages/{}", channel_id.as_ref(), msg_id.as_ref());
+    // GET/guilds/{guild.id}/regions
+    pub(crate) fn get_guild_voice_regions(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/regions", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
 
-        Self {
+    // GET/guilds/{guild.id}/vanity-url
+    pub(crate) fn get_guild_vanity_url(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/vanity-url", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // POST/channels/{channel.id}/messages/bulk-delete
-    pub(crate) fn bulk_delete_messages(channel_id: impl AsRef<str>, body: B) -> Self {
-        let method = Method::POST;
-        let uri = api_request!("/channels/{}/messages/bulk-delete", channel_id.as_ref());
+    // GET/guilds/{guild.id}/widget
+    pub(crate) fn get_guild_widget(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/widget", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/members/{user.id}
+    pub(crate) fn get_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/members/{}", guild_id.as_ref(), user_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/members
+    pub(crate) fn list_guild_members(guild_id: impl AsRef<str>, after: impl AsRef<str>, limit: u16) -> Self {
+        let method = Method::GET;
+        let uri = api_request!(
+            "/guilds/{}/members?after={}&limit={}",
+            guild_id.as_ref(),
+            after.as_ref(),
+            limit
+        );
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/members/search
+    pub(crate) fn search_guild_members(guild_id: impl AsRef<str>, query: &str) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/members/search?{}", guild_id.as_ref(), query);
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // PUT/guilds/{guild.id}/members/{user.id}/roles/{role.id}
+    pub(crate) fn add_guild_member_role(
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Self {
+        let method = Method::PUT;
+        let uri = api_request!(
+            "/guilds/{}/members/{}/roles/{}",
+            guild_id.as_ref(),
+            user_id.as_ref(),
+            role_id.as_ref()
+        );
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/members/{user.id}/roles/{role.id}
+    pub(crate) fn remove_guild_member_role(
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!(
+            "/guilds/{}/members/{}/roles/{}",
+            guild_id.as_ref(),
+            user_id.as_ref(),
+            role_id.as_ref()
+        );
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/members/{user.id}
+    pub(crate) fn remove_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/members/{}", guild_id.as_ref(), user_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/bans
+    pub(crate) fn get_guild_bans(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/bans", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
 
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/bans/{user.id}
+    pub(crate) fn get_guild_ban(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/bans/{}", guild_id.as_ref(), user_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // PUT/guilds/{guild.id}/bans/{user.id}
+    pub(crate) fn create_guild_ban(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, query: &str) -> Self {
+        let method = Method::PUT;
+        let uri = api_request!("/guilds/{}/bans/{}?{}", guild_id.as_ref(), user_id.as_ref(), query);
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/bans/{user.id}
+    pub(crate) fn remove_guild_ban(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/bans/{}", guild_id.as_ref(), user_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/roles
+    pub(crate) fn get_guild_roles(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/roles", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/roles/{role.id}
+    pub(crate) fn delete_guild_role(guild_id: impl AsRef<str>, role_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/roles/{}", guild_id.as_ref(), role_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/channels/{channel.id}/permissions/{overwrite.id}
+    pub(crate) fn delete_channel_permission(channel_id: impl AsRef<str>, overwrite_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!(
+            "/channels/{}/permissions/{}",
+            channel_id.as_ref(),
+            overwrite_id.as_ref()
+        );
         let bucket_key = bucket_key!(channel: channel_id);
 
         Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // PUT/channels/{channel.id}/permissions/{overwrite.id}
-    // TODO: Check {overwrite.id}
-    // pub(crate) fn edit_channel_permissions(
-    //     channel_id: impl AsRef<str>,
-    //     overwrite_id: impl AsRef<str>,
-    //     body: B,
-    // ) -> Self {
-    //     let method = Method::PUT;
-    //     let uri = api_request!(
-    //         "/channels/{}/permissions/{}",
-    //         channel_id.as_ref(),
-    //         overwrite_id.as_ref()
-    //     );
-
-    //     let bucket_key = bucket_key!(channel: channel_id);
-
-    //     Route {
-    //         method,
-    //         uri,
-    //         bucket_key,
-    //         body,
-    //     }
+    // GET/invites/{invite.code}
+    pub(crate) fn get_invite(invite_code: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/invites/{}?with_counts=true", invite_code.as_ref());
+        let bucket_key = bucket_key!(invite: invite_code);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/invites/{invite.code}
+    pub(crate) fn delete_invite(invite_code: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/invites/{}", invite_code.as_ref());
+        let bucket_key = bucket_key!(invite: invite_code);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/users/@me
+    pub(crate) fn get_current_user() -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/users/@me");
+        let bucket_key = bucket_key!(current_user);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/users/@me/guilds
+    pub(crate) fn get_current_user_guilds(after: impl AsRef<str>, limit: u8) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/users/@me/guilds?after={}&limit={}", after.as_ref(), limit);
+        let bucket_key = bucket_key!(current_user);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/users/@me/guilds/{guild.id}
+    pub(crate) fn leave_guild(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/users/@me/guilds/{}", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/users/{user.id}
+    pub(crate) fn get_user(user_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/users/{}", user_id.as_ref());
+        let bucket_key = bucket_key!(user: user_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/channels/{channel.id}/recipients/{user.id}
+    pub(crate) fn group_dm_remove_recipient(channel_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/channels/{}/recipients/{}", channel_id.as_ref(), user_id.as_ref());
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/emojis
+    pub(crate) fn list_guild_emojis(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/emojis", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/emojis/{emoji.id}
+    pub(crate) fn get_guild_emoji(guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/emojis/{}", guild_id.as_ref(), emoji_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/emojis/{emoji.id}
+    pub(crate) fn delete_guild_emoji(guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/emojis/{}", guild_id.as_ref(), emoji_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/channels/{channel.id}/webhooks
+    pub(crate) fn get_channel_webhooks(channel_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/channels/{}/webhooks", channel_id.as_ref());
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/webhooks
+    pub(crate) fn get_guild_webhooks(guild_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/webhooks", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/webhooks/{webhook.id}
+    pub(crate) fn get_webhook(webhook_id: impl AsRef<str>) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/webhooks/{}", webhook_id.as_ref());
+        let bucket_key = bucket_key!(webhook: webhook_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/webhooks/{webhook.id}
+    pub(crate) fn delete_webhook(webhook_id: impl AsRef<str>) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!("/webhooks/{}", webhook_id.as_ref());
+        let bucket_key = bucket_key!(webhook: webhook_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/webhooks/{webhook.id}/{webhook.token}/messages/{message.id}
+    pub(crate) fn delete_webhook_message(
+        webhook_id: impl AsRef<str>,
+        webhook_token: impl AsRef<str>,
+        message_id: impl AsRef<str>,
+    ) -> Self {
+        let method = Method::DELETE;
+        let uri = api_request!(
+            "/webhooks/{}/{}/messages/{}",
+            webhook_id.as_ref(),
+            webhook_token.as_ref(),
+            message_id.as_ref()
+        );
+        let bucket_key = bucket_key!(webhook: webhook_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/audit-logs
+    pub(crate) fn get_guild_audit_log(guild_id: impl AsRef<str>, query: &str) -> Self {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/audit-logs?{}", guild_id.as_ref(), query);
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+}
+
+// Routes with body
+impl<B: Into<Body>> Route<B> {
+    // pub(crate) fn as_request(self, token: &str) -> (String, Request<Body>) {
+    //     // let request = match self.method {
+    //     //     Method::GET | Method::PUT | Method::DELETE => Request::builder()
+    //     //         .method(&self.method)
+    //     //         .uri(&self.uri)
+    //     //         .header("Authorization", token)
+    //     //         .body(())
+    //     //         .unwrap(),
+    //     //     Method::POST | Method::PATCH => Request::builder()
+    //     //         .method(&self.method)
+    //     //         .uri(&self.uri)
+    //     //         .header("Authorization", token)
+    //     //         .header("Content-Type", "application/json")
+    //     //         .body(self.body.into())
+    //     //         .unwrap(),
+    //     //     _ => unimplemented!(),
+    //     // };
+
+    //     let request = match self.body {
+    //         () => Request::builder()
+    //             .method(&self.method)
+    //             .uri(&self.uri)
+    //             .header("Authorization", token)
+    //             .body(())
+    //             .unwrap(),
+    //         _ => Request::builder()
+    //             .method(&self.method)
+    //             .uri(&self.uri)
+    //             .header("Authorization", token)
+    //             .header("Content-Type", "application/json")
+    //             .body(self.body)
+    //             .unwrap(),
+    //     };
+
+    //     println!("{:?}", request);
+
+    //     (self.bucket_key, request)
     // }
+
+    // PATCH/channels/{channel.id}
+    pub(crate) fn edit_channel(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/channels/{}", channel_id.as_ref());
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Self {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/messages
+    pub(crate) fn create_message(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/messages", channel_id.as_ref());
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Self {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/channels/{channel.id}/messages/{message.id}
+    pub(crate) fn edit_message(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/channels/{}/messages/{}", channel_id.as_ref(), msg_id.as_ref());
+
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Self {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/messages/bulk-delete
+    pub(crate) fn bulk_delete_messages(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/messages/bulk-delete", channel_id.as_ref());
+
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}
+    pub(crate) fn modify_guild(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/guilds/{guild.id}/channels
+    pub(crate) fn create_guild_channel(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/guilds/{}/channels", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/channels
+    pub(crate) fn modify_guild_channel_positions(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/channels", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/widget
+    pub(crate) fn modify_guild_widget(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/widget", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/members/{user.id}
+    pub(crate) fn modify_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/members/{}", guild_id.as_ref(), user_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/members/@me/nick
+    pub(crate) fn modify_current_user_nick(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/members/@me/nick", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/guilds/{guild.id}/roles
+    pub(crate) fn create_guild_role(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/guilds/{}/roles", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/roles
+    pub(crate) fn modify_guild_role_positions(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/roles", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/roles/{role.id}
+    pub(crate) fn modify_guild_role(guild_id: impl AsRef<str>, role_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/roles/{}", guild_id.as_ref(), role_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/invites
+    pub(crate) fn create_channel_invite(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/invites", channel_id.as_ref());
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PUT/channels/{channel.id}/permissions/{overwrite.id}
+    pub(crate) fn edit_channel_permissions(
+        channel_id: impl AsRef<str>,
+        overwrite_id: impl AsRef<str>,
+        body: B,
+    ) -> Self {
+        let method = Method::PUT;
+        let uri = api_request!(
+            "/channels/{}/permissions/{}",
+            channel_id.as_ref(),
+            overwrite_id.as_ref()
+        );
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/users/@me
+    pub(crate) fn modify_current_user(body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/users/@me");
+        let bucket_key = bucket_key!(current_user);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/users/@me/channels
+    pub(crate) fn create_dm(body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/users/@me/channels");
+        let bucket_key = bucket_key!(current_user);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PUT/channels/{channel.id}/recipients/{user.id}
+    pub(crate) fn group_dm_add_recipient(channel_id: impl AsRef<str>, user_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PUT;
+        let uri = api_request!("/channels/{}/recipients/{}", channel_id.as_ref(), user_id.as_ref());
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/guilds/{guild.id}/emojis
+    pub(crate) fn create_guild_emoji(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/guilds/{}/emojis", guild_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/emojis/{emoji.id}
+    pub(crate) fn modify_guild_emoji(guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/emojis/{}", guild_id.as_ref(), emoji_id.as_ref());
+        let bucket_key = bucket_key!(guild: guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/webhooks
+    pub(crate) fn create_webhook(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/webhooks", channel_id.as_ref());
+        let bucket_key = bucket_key!(channel: channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/webhooks/{webhook.id}
+    pub(crate) fn modify_webhook(webhook_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/webhooks/{}", webhook_id.as_ref());
+        let bucket_key = bucket_key!(webhook: webhook_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/webhooks/{webhook.id}/{webhook.token}
+    pub(crate) fn execute_webhook(webhook_id: impl AsRef<str>, webhook_token: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/webhooks/{}/{}?wait=true", webhook_id.as_ref(), webhook_token.as_ref());
+        let bucket_key = bucket_key!(webhook: webhook_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/webhooks/{webhook.id}/{webhook.token}/messages/{message.id}
+    pub(crate) fn edit_webhook_message(
+        webhook_id: impl AsRef<str>,
+        webhook_token: impl AsRef<str>,
+        message_id: impl AsRef<str>,
+        body: B,
+    ) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!(
+            "/webhooks/{}/{}/messages/{}",
+            webhook_id.as_ref(),
+            webhook_token.as_ref(),
+            message_id.as_ref()
+        );
+        let bucket_key = bucket_key!(webhook: webhook_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
 }
 
 /// Used to encode emoji as a valid char in URL
-fn encode(data: impl AsRef<str>) -> String {
+pub(crate) fn encode(data: impl AsRef<str>) -> String {
     let mut escaped = String::new();
     for b in data.as_ref().as_bytes().iter() {
         match *b as char {
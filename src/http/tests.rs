@@ -0,0 +1,460 @@
+//! Tests of the rate limiter, the error responses and the request bodies, the requests are sent
+//! to a local HTTP server that returns scripted responses
+
+use super::{make_request, rate_limit::RateLimit, routing::Route};
+use crate::error::{DiscordErrorCode, FieldError, PandaError};
+
+use isahc::{
+    config::Configurable,
+    http::{Method, StatusCode},
+    HttpClient as IsachClient,
+};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+struct Scripted {
+    status: u16,
+    headers: Vec<(&'static str, &'static str)>,
+    body: &'static str,
+}
+
+fn ok(headers: Vec<(&'static str, &'static str)>) -> Scripted {
+    Scripted {
+        status: 200,
+        headers,
+        body: "{}",
+    }
+}
+
+fn too_many_requests(headers: Vec<(&'static str, &'static str)>) -> Scripted {
+    Scripted {
+        status: 429,
+        headers,
+        body: "{}",
+    }
+}
+
+fn error(status: u16, body: &'static str) -> Scripted {
+    Scripted {
+        status,
+        headers: vec![],
+        body,
+    }
+}
+
+/// A received request, the request line and the body
+type Received = (String, Vec<u8>);
+
+/// Start a server that answers each connection with the next scripted response, it stops when
+/// all responses were sent
+fn stand_in(script: Vec<Scripted>) -> (String, Arc<Mutex<Vec<Received>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Can't bind stand-in server");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+
+    thread::spawn(move || {
+        for response in script {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+
+                let lower = line.to_ascii_lowercase();
+                if let Some(value) = lower.strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            received_clone
+                .lock()
+                .unwrap()
+                .push((request_line.trim().to_string(), body));
+
+            let mut head = format!("HTTP/1.1 {} Scripted\r\n", response.status);
+            for (name, value) in response.headers {
+                head.push_str(&format!("{}: {}\r\n", name, value));
+            }
+            head.push_str(&format!(
+                "Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.body.len(),
+                response.body
+            ));
+
+            let mut stream = reader.into_inner();
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.flush().unwrap();
+        }
+    });
+
+    (url, received)
+}
+
+fn client() -> IsachClient {
+    IsachClient::builder().proxy(None).build().unwrap()
+}
+
+fn get(url: &str, path: &str, major: &str) -> Route<()> {
+    Route {
+        method: Method::GET,
+        uri: format!("{}{}", url, path),
+        bucket_key: major.to_string(),
+        body: (),
+    }
+}
+
+#[tokio::test]
+async fn waits_until_exhausted_bucket_resets() {
+    let (url, received) = stand_in(vec![
+        ok(vec![
+            ("X-RateLimit-Bucket", "abc"),
+            ("X-RateLimit-Limit", "1"),
+            ("X-RateLimit-Remaining", "0"),
+            ("X-RateLimit-Reset-After", "0.3"),
+        ]),
+        ok(vec![]),
+    ]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let route = get(&url, "/channels/1/messages/10", "channels:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    // Same route with other message ID, it's the same bucket
+    let start = Instant::now();
+    let route = get(&url, "/channels/1/messages/11", "channels:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(250));
+    assert_eq!(received.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn other_major_parameter_is_not_limited() {
+    let (url, _) = stand_in(vec![
+        ok(vec![
+            ("X-RateLimit-Bucket", "abc"),
+            ("X-RateLimit-Limit", "1"),
+            ("X-RateLimit-Remaining", "0"),
+            ("X-RateLimit-Reset-After", "5"),
+        ]),
+        ok(vec![]),
+    ]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let route = get(&url, "/channels/1/messages", "channels:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    let start = Instant::now();
+    let route = get(&url, "/channels/2/messages", "channels:2");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn routes_with_the_same_bucket_hash_share_the_limit() {
+    let (url, _) = stand_in(vec![
+        ok(vec![
+            ("X-RateLimit-Bucket", "shared"),
+            ("X-RateLimit-Limit", "5"),
+            ("X-RateLimit-Remaining", "4"),
+            ("X-RateLimit-Reset-After", "0.3"),
+        ]),
+        ok(vec![
+            ("X-RateLimit-Bucket", "shared"),
+            ("X-RateLimit-Limit", "5"),
+            ("X-RateLimit-Remaining", "0"),
+            ("X-RateLimit-Reset-After", "0.3"),
+        ]),
+        ok(vec![]),
+    ]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let route = get(&url, "/guilds/1/members", "guild:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    let route = get(&url, "/guilds/1/bans", "guild:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    // The bucket was exhausted by the other route
+    let start = Instant::now();
+    let route = get(&url, "/guilds/1/members", "guild:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(250));
+}
+
+#[tokio::test]
+async fn retries_after_too_many_requests() {
+    let (url, received) = stand_in(vec![
+        too_many_requests(vec![
+            ("X-RateLimit-Bucket", "abc"),
+            ("X-RateLimit-Limit", "5"),
+            ("X-RateLimit-Remaining", "0"),
+            ("X-RateLimit-Reset-After", "0.2"),
+            ("Retry-After", "1"),
+        ]),
+        ok(vec![]),
+    ]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let start = Instant::now();
+    let route = Route {
+        method: Method::POST,
+        uri: format!("{}/channels/1/messages", url),
+        bucket_key: "channels:1".to_string(),
+        body: String::from(r#"{"content":"hi"}"#),
+    };
+    let response = make_request(&client, &rate_limit, None, None, "application/json", route)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(start.elapsed() >= Duration::from_millis(150));
+
+    // The body is sent again
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0], received[1]);
+    assert_eq!(received[1].1, br#"{"content":"hi"}"#.to_vec());
+}
+
+#[tokio::test]
+async fn global_rate_limit_blocks_other_routes() {
+    let (url, _) = stand_in(vec![
+        too_many_requests(vec![("X-RateLimit-Global", "true"), ("Retry-After", "0.3")]),
+        ok(vec![]),
+        ok(vec![]),
+    ]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let first = {
+        let (client, rate_limit) = (client.clone(), rate_limit.clone());
+        let route = get(&url, "/channels/1/messages", "channels:1");
+
+        tokio::spawn(async move { make_request(&client, &rate_limit, None, None, "", route).await })
+    };
+
+    // Wait for the 429 response
+    tokio::time::delay_for(Duration::from_millis(100)).await;
+
+    let start = Instant::now();
+    let route = get(&url, "/guilds/1", "guild:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(150));
+    assert!(first.await.unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn retry_after_is_in_seconds() {
+    use isahc::{http::Response, Body};
+
+    let too_many_requests = |global: &str| {
+        Response::builder()
+            .status(429)
+            .header("Retry-After", "2")
+            .header("X-RateLimit-Global", global)
+            .body(Body::empty())
+            .unwrap()
+    };
+    let rate_limit = RateLimit::default();
+
+    // Without X-RateLimit-Reset-After the bucket waits for Retry-After too
+    let wait = rate_limit
+        .update("GET /channels/{id}", "channels:1", &too_many_requests("false"))
+        .await;
+    assert_eq!(wait, Some(Duration::from_secs(2)));
+
+    let wait = rate_limit
+        .update("GET /channels/{id}", "channels:1", &too_many_requests("true"))
+        .await;
+    assert_eq!(wait, Some(Duration::from_secs(2)));
+}
+
+#[tokio::test]
+async fn gives_up_after_max_retries() {
+    let script = (0..=super::rate_limit::MAX_RETRIES)
+        .map(|_| too_many_requests(vec![("X-RateLimit-Reset-After", "0.01"), ("Retry-After", "1")]))
+        .collect();
+    let (url, received) = stand_in(script);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let route = get(&url, "/channels/1", "channels:1");
+    let result = make_request(&client, &rate_limit, None, None, "", route).await;
+
+    assert!(matches!(result, Err(PandaError::HttpRateLimited)));
+    assert_eq!(
+        received.lock().unwrap().len() as u32,
+        super::rate_limit::MAX_RETRIES + 1
+    );
+}
+
+#[tokio::test]
+async fn malformed_headers_are_ignored() {
+    let (url, _) = stand_in(vec![
+        ok(vec![
+            ("X-RateLimit-Bucket", "abc"),
+            ("X-RateLimit-Limit", "-1"),
+            ("X-RateLimit-Remaining", "none"),
+            ("X-RateLimit-Reset-After", "NaN"),
+        ]),
+        too_many_requests(vec![("Retry-After", "soon"), ("X-RateLimit-Global", "yes")]),
+        ok(vec![]),
+    ]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let route = get(&url, "/channels/1", "channels:1");
+    make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    // Without a valid wait, the default one is used
+    let route = get(&url, "/channels/1", "channels:1");
+    let response = make_request(&client, &rate_limit, None, None, "", route).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn error_responses_have_the_discord_error() {
+    let (url, _) = stand_in(vec![error(403, r#"{"message": "Missing Permissions", "code": 50013}"#)]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let route = get(&url, "/channels/1/messages/2", "channels:1");
+    let error = match make_request(&client, &rate_limit, None, None, "", route).await {
+        Err(PandaError::Http(error)) => error,
+        _ => panic!("Expected an http error"),
+    };
+
+    assert_eq!(error.status, 403);
+    assert_eq!(error.method, "GET");
+    assert_eq!(error.route, "/channels/{id}/messages/{id}");
+    assert_eq!(error.code, Some(DiscordErrorCode::MissingPermissions));
+    assert_eq!(error.message.as_deref(), Some("Missing Permissions"));
+    assert!(error.errors.is_empty());
+}
+
+#[tokio::test]
+async fn error_responses_have_the_field_errors() {
+    let body = r#"{
+        "code": 50035,
+        "message": "Invalid Form Body",
+        "errors": {
+            "content": {"_errors": [{"code": "BASE_TYPE_MAX_LENGTH", "message": "Too long"}]},
+            "embed": {"fields": {"0": {"name": {"_errors": [{"code": "BASE_TYPE_REQUIRED", "message": "Required"}]}}}}
+        }
+    }"#;
+    let (url, _) = stand_in(vec![error(400, body), error(502, "<html>Bad Gateway</html>")]);
+    let (client, rate_limit) = (client(), RateLimit::default());
+
+    let route = get(&url, "/channels/1/messages", "channels:1");
+    let error = match make_request(&client, &rate_limit, None, None, "", route).await {
+        Err(PandaError::Http(error)) => error,
+        _ => panic!("Expected an http error"),
+    };
+
+    assert_eq!(error.code, Some(DiscordErrorCode::InvalidFormBody));
+    assert_eq!(
+        error.errors,
+        vec![
+            FieldError {
+                path: "content".into(),
+                code: "BASE_TYPE_MAX_LENGTH".into(),
+                message: "Too long".into(),
+            },
+            FieldError {
+                path: "embed.fields.0.name".into(),
+                code: "BASE_TYPE_REQUIRED".into(),
+                message: "Required".into(),
+            },
+        ]
+    );
+
+    // Not every error response has a JSON body
+    let route = get(&url, "/channels/1/messages", "channels:1");
+    let error = match make_request(&client, &rate_limit, None, None, "", route).await {
+        Err(PandaError::Http(error)) => error,
+        _ => panic!("Expected an http error"),
+    };
+
+    assert_eq!(error.status, 502);
+    assert_eq!(error.code, None);
+    assert!(error.errors.is_empty());
+}
+
+#[test]
+fn route_keys_ignore_ids_and_queries() {
+    use super::rate_limit::route_key;
+
+    assert_eq!(
+        route_key(&Method::GET, "https://discord.com/api/v6/channels/1/messages/2?limit=5"),
+        "GET /api/v6/channels/{id}/messages/{id}"
+    );
+    assert_eq!(
+        route_key(&Method::DELETE, "http://127.0.0.1:8080/guilds/3/bans/4"),
+        "DELETE /guilds/{id}/bans/{id}"
+    );
+    assert_eq!(
+        route_key(
+            &Method::POST,
+            "https://discord.com/api/v6/webhooks/5/s3cr3t-t0k3n?wait=true"
+        ),
+        "POST /api/v6/webhooks/{id}/{token}"
+    );
+    assert_ne!(
+        route_key(&Method::GET, "/channels/1/messages"),
+        route_key(&Method::POST, "/channels/1/messages")
+    );
+}
+
+#[test]
+fn default_allowed_mentions_are_added_to_edits() {
+    use crate::{
+        models::channel::{AllowedMentions, MentionKind},
+        utils::builders::MessageEdit,
+    };
+
+    let http = super::HttpClient::new("token");
+    http.set_allowed_mentions(AllowedMentions::new().parse(MentionKind::Users));
+
+    let mut edit = serde_json::to_value(MessageEdit::new().content("@everyone")).unwrap();
+    http.apply_allowed_mentions(&mut edit);
+    assert_eq!(edit["allowed_mentions"]["parse"], serde_json::json!(["users"]));
+
+    // The allowed mentions of the edit have priority
+    let own = MessageEdit::new().allowed_mentions(AllowedMentions::new());
+    let mut edit = serde_json::to_value(own).unwrap();
+    http.apply_allowed_mentions(&mut edit);
+    assert_eq!(edit["allowed_mentions"]["parse"], serde_json::json!([]));
+}
+
+#[test]
+fn prune_count_must_be_sent() {
+    use super::PruneCount;
+
+    let count: PruneCount<u64> = serde_json::from_str(r#"{"pruned": 3}"#).unwrap();
+    assert_eq!(count.pruned, 3);
+    assert!(serde_json::from_str::<PruneCount<u64>>("{}").is_err());
+    assert!(serde_json::from_str::<PruneCount<u64>>(r#"{"pruned": null}"#).is_err());
+
+    // Without compute_prune_count the count of a prune is null
+    let count: PruneCount<Option<u64>> = serde_json::from_str(r#"{"pruned": null}"#).unwrap();
+    assert_eq!(count.pruned, None);
+}
@@ -0,0 +1,97 @@
+use super::{make_request, rate_limit::RateLimit, routing::Route};
+use crate::{
+    error::{PandaError, Result},
+    models::channel::Message,
+};
+
+use isahc::{prelude::*, HttpClient as IsachClient};
+use serde::Serialize;
+use url::Url;
+
+/// A client to send messages with a webhook, it only needs the ID and the token of the webhook,
+/// so it can be used without a bot token. It respects the rate limits like [`HttpClient`].
+///
+/// [`HttpClient`]: ../../panda/struct.HttpClient.html
+pub struct WebhookClient {
+    id: String,
+    token: String,
+    client: IsachClient,
+    rate_limit: RateLimit,
+}
+
+impl WebhookClient {
+    /// Creates a new webhook client with the ID and the token of the webhook
+    pub fn new(id: impl Into<String>, token: impl Into<String>) -> WebhookClient {
+        let client = IsachClient::new().expect("Can't create Http Client");
+
+        WebhookClient {
+            id: id.into(),
+            token: token.into(),
+            client,
+            rate_limit: RateLimit::default(),
+        }
+    }
+
+    /// Creates a new webhook client from a webhook URL, like
+    /// `https://discord.com/api/webhooks/{webhook.id}/{webhook.token}`
+    pub fn from_url(url: impl AsRef<str>) -> Result<WebhookClient> {
+        let url = Url::parse(url.as_ref()).map_err(|_| PandaError::InvalidWebhookUrl)?;
+        let mut segments = url
+            .path_segments()
+            .ok_or(PandaError::InvalidWebhookUrl)?
+            .skip_while(|s| *s != "webhooks")
+            .skip(1);
+
+        match (segments.next(), segments.next()) {
+            (Some(id), Some(token)) if !id.is_empty() && !token.is_empty() => Ok(WebhookClient::new(id, token)),
+            _ => Err(PandaError::InvalidWebhookUrl),
+        }
+    }
+
+    /// Returns the ID of the webhook
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Execute the webhook, returns the sent [`Message`]. It's recommended to use [`WebhookMessage`]
+    /// builder, it can override the username and the avatar of the webhook.
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`WebhookMessage`]: ../../panda/utils/builders/struct.WebhookMessage.html
+    pub async fn execute(&self, body: impl Serialize) -> Result<Message> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::execute_webhook(&self.id, &self.token, body);
+
+        let mut res = make_request(&self.client, &self.rate_limit, None, None, "application/json", route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Shortcut to execute the webhook with only a content
+    pub async fn send(&self, content: impl AsRef<str>) -> Result<Message> {
+        self.execute(serde_json::json!({ "content": content.as_ref() })).await
+    }
+
+    /// Edit a message sent by the webhook, returns the updated [`Message`]. It's recommended to use
+    /// [`WebhookMessage`] builder, only the content and the embeds can be edited.
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`WebhookMessage`]: ../../panda/utils/builders/struct.WebhookMessage.html
+    pub async fn edit_message(&self, message_id: impl AsRef<str>, body: impl Serialize) -> Result<Message> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::edit_webhook_message(&self.id, &self.token, message_id, body);
+
+        let mut res = make_request(&self.client, &self.rate_limit, None, None, "application/json", route).await?;
+
+        Ok(res.json()?)
+    }
+
+    /// Delete a message sent by the webhook
+    pub async fn delete_message(&self, message_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::delete_webhook_message(&self.id, &self.token, message_id);
+
+        let _res = make_request(&self.client, &self.rate_limit, None, None, "application/json", route).await?;
+
+        Ok(())
+    }
+}
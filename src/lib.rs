@@ -63,6 +63,7 @@ macro_rules! define_cfg {
 
 define_cfg! {
     // Modules
+    pub mod cache;
     #[doc(inline)]
     pub mod client;
     #[doc(inline)]
@@ -75,8 +76,8 @@ define_cfg! {
     mod http;
     mod runtime;
 
-    pub use error::PandaError;
-    pub use http::HttpClient;
+    pub use error::{DiscordErrorCode, FieldError, HttpError, PandaError};
+    pub use http::{AttachmentFile, HttpClient, WebhookClient};
 
     // Re-exports
     pub use models::gateway::events;
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+/// Controls which mentions of a message will notify the users. By default nobody is notified,
+/// use [`AllowedMentions::all`] to notify everyone mentioned, like when `allowed_mentions` is not sent.
+///
+/// [`AllowedMentions::all`]: struct.AllowedMentions.html#method.all
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct AllowedMentions {
+    /// Kinds of mentions parsed from the content
+    #[serde(default)]
+    pub parse: Vec<MentionKind>,
+    /// Users that can be mentioned, up to 100. It can't be used with [`MentionKind::Users`]
+    ///
+    /// [`MentionKind::Users`]: enum.MentionKind.html#variant.Users
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<String>,
+    /// Roles that can be mentioned, up to 100. It can't be used with [`MentionKind::Roles`]
+    ///
+    /// [`MentionKind::Roles`]: enum.MentionKind.html#variant.Roles
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    /// Mention the author of the replied message
+    #[serde(default)]
+    pub replied_user: bool,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MentionKind {
+    Roles,
+    Users,
+    Everyone,
+}
+
+impl AllowedMentions {
+    /// Nobody is notified
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All mentions of the content notify the users, including `@everyone` and `@here`
+    pub fn all() -> Self {
+        AllowedMentions {
+            parse: vec![MentionKind::Roles, MentionKind::Users, MentionKind::Everyone],
+            users: Vec::new(),
+            roles: Vec::new(),
+            replied_user: true,
+        }
+    }
+
+    /// Notify all mentions of this kind
+    pub fn parse(mut self, kind: MentionKind) -> Self {
+        if !self.parse.contains(&kind) {
+            self.parse.push(kind);
+        }
+
+        self
+    }
+
+    /// Notify this user if it's mentioned
+    pub fn user(mut self, user_id: impl Into<String>) -> Self {
+        self.users.push(user_id.into());
+
+        self
+    }
+
+    /// Notify the members of this role if it's mentioned
+    pub fn role(mut self, role_id: impl Into<String>) -> Self {
+        self.roles.push(role_id.into());
+
+        self
+    }
+
+    /// Notify the author of the replied message
+    pub fn replied_user(mut self, replied_user: bool) -> Self {
+        self.replied_user = replied_user;
+
+        self
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::default::Default;
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct Embed {
     /// Title of the embed
     pub title: Option<String>,
@@ -17,17 +17,18 @@ pub struct Embed {
     pub video: Option<EmbedVideo>,
     pub provider: Option<EmbedProvider>,
     pub author: Option<EmbedAuthor>,
+    #[serde(default)]
     pub fields: Vec<EmbedField>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedFooter {
     text: String,
     icon_url: Option<String>,
     proxy_icon_url: Option<String>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedImage {
     url: Option<String>,
     proxy_url: Option<String>,
@@ -35,7 +36,7 @@ pub struct EmbedImage {
     width: Option<u64>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedThumbnail {
     url: Option<String>,
     proxy_url: Option<String>,
@@ -43,20 +44,20 @@ pub struct EmbedThumbnail {
     width: Option<u64>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedVideo {
     url: Option<String>,
     height: Option<u64>,
     width: Option<u64>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedProvider {
     name: Option<String>,
     url: Option<String>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedAuthor {
     name: Option<String>,
     url: Option<String>,
@@ -64,7 +65,7 @@ pub struct EmbedAuthor {
     proxy_icon_url: Option<String>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedField {
     name: String,
     value: String,
@@ -2,6 +2,7 @@ use crate::{
     error::Result,
     http::HttpClient,
     models::{guild::GuildMember, user::User},
+    utils::builders::CreateMessage,
 };
 
 use super::{Embed, MentionChannel, MessageReference, Attachment, Reaction, MessageApplication};
@@ -9,7 +10,7 @@ use super::{Embed, MentionChannel, MessageReference, Attachment, Reaction, Messa
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 /// Represents a message sent in a channel within Discord.
 /// [Discord Documentation](https://discord.com/developers/docs/resources/channel#message-object)
 pub struct Message {
@@ -89,7 +90,7 @@ pub struct Message {
     pub flags: Option<u64>,
 }
 
-#[derive(Debug, Serialize_repr, Deserialize_repr)]
+#[derive(Clone, Debug, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum MessageKind {
     Regular = 0,
@@ -125,6 +126,15 @@ impl Message {
         http.send_embed(&self.channel_id, embed).await
     }
 
+    /// Reply to the message, it's sent to the same channel. Shortcut for [`HttpClient.create_message`]
+    ///
+    /// [`HttpClient.create_message`]: ../../../struct.HttpClient.html#method.create_message
+    pub async fn reply(&self, http: &HttpClient, content: impl Into<String>) -> Result<Message> {
+        let message = CreateMessage::new().content(content).reference(self);
+
+        http.create_message(&self.channel_id, message).await
+    }
+
     /// Shortcut for [`HttpClient.add_reaction`]
     ///
     /// [`HttpClient.add_reaction`]: ../../../struct.HttpClient.html#method.add_reaction
@@ -1,5 +1,6 @@
 //! Channel related models
 
+mod allowed_mentions;
 mod attachment;
 mod embed;
 mod message;
@@ -9,6 +10,7 @@ mod overwrite;
 mod reaction;
 
 // Re-exports
+pub use allowed_mentions::{AllowedMentions, MentionKind};
 pub use attachment::Attachment;
 pub use embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedProvider, EmbedThumbnail, EmbedVideo};
 pub use message::Message;
@@ -1,4 +1,3 @@
-use crate::models::guild::Role;
 use crate::models::user::User;
 use serde::{Deserialize, Serialize};
 
@@ -7,7 +6,7 @@ pub struct Emoji {
     pub id: Option<String>,
     pub name: Option<String>,
     #[serde(default)]
-    pub roles: Vec<Role>,
+    pub roles: Vec<String>,
     pub user: Option<User>,
     pub require_colons: Option<bool>,
     pub managed: Option<bool>,
@@ -3,16 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use std::ops::Deref;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildBan {
-    guild_id: String,
-    user: User,
+    pub guild_id: String,
+    pub user: User,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildBanAdd(GuildBan);
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildBanRemove(GuildBan);
 
 impl Deref for GuildBanAdd {
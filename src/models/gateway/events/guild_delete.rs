@@ -3,5 +3,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildDelete {
     pub id: String,
+    /// `true` if the guild is unavailable because of an outage, it's `false` when the user
+    /// left the guild or was removed from it
+    #[serde(default)]
     pub unavailable: bool,
 }
@@ -1,7 +1,7 @@
 use crate::models::user::User;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildMemberRemove {
     pub guild_id: String,
     pub user: User,
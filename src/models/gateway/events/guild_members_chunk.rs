@@ -1,12 +1,16 @@
-use crate::models::guild::GuildMember;
+use crate::models::{guild::GuildMember, user::Presence};
 use serde::{Deserialize, Serialize};
 
-// TODO: implement presences
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildMembersChunk {
     pub guild_id: String,
     pub members: Vec<GuildMember>,
+    pub chunk_index: Option<u64>,
+    pub chunk_count: Option<u64>,
     #[serde(default)]
     pub not_found: Vec<String>,
-    //presences: Vec<Presence>
+    /// Only sent if `presences` was requested in RequestGuildMembers
+    #[serde(default)]
+    pub presences: Vec<Presence>,
+    pub nonce: Option<String>,
 }
@@ -1,7 +1,7 @@
 use crate::models::guild::Role;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildRoleCreate {
     pub guild_id: String,
     pub role: Role,
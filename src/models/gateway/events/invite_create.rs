@@ -0,0 +1,17 @@
+use crate::models::user::User;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InviteCreate {
+    pub channel_id: String,
+    pub code: String,
+    pub created_at: String,
+    pub guild_id: Option<String>,
+    pub inviter: Option<User>,
+    pub max_age: u64,
+    pub max_uses: u64,
+    pub target_user: Option<User>,
+    pub target_user_type: Option<u8>,
+    pub temporary: bool,
+    pub uses: u64,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InviteDelete {
+    pub channel_id: String,
+    pub guild_id: Option<String>,
+    pub code: String,
+}
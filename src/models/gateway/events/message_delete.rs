@@ -1,8 +1,13 @@
+use crate::models::Message;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageDelete {
     pub id: String,
     pub channel_id: String,
     pub guild_id: Option<String>,
+
+    /// The deleted message, only if it was in the message cache
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub old_message: Option<Message>,
 }
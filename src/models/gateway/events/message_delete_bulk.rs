@@ -1,8 +1,13 @@
+use crate::models::Message;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageDeleteBulk {
     pub ids: Vec<String>,
     pub channel_id: String,
     pub guild_id: Option<String>,
+
+    /// The deleted messages that were in the message cache
+    #[serde(default, skip_deserializing, skip_serializing_if = "Vec::is_empty")]
+    pub old_messages: Vec<Message>,
 }
@@ -1,7 +1,7 @@
 use crate::models::emoji::Emoji;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReactionRemoveEmoji {
     pub channel_id: String,
     pub guild_id: Option<String>,
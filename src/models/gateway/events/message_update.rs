@@ -1,12 +1,18 @@
-use crate::models::Embed;
+use crate::models::{Embed, Message};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUpdate {
     pub id: String,
     pub channel_id: String,
     pub guild_id: Option<String>,
+    pub content: Option<String>,
+    pub edited_timestamp: Option<String>,
 
-    #[serde(default)]
-    pub embeds: Vec<Embed>,
+    /// `None` if the embeds didn't change
+    pub embeds: Option<Vec<Embed>>,
+
+    /// The message before the update, only if it was in the message cache
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub old_message: Option<Message>,
 }
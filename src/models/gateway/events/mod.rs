@@ -19,6 +19,10 @@ mod guild_role_delete;
 mod guild_role_update;
 mod guild_update;
 
+// Invite
+mod invite_create;
+mod invite_delete;
+
 // Message
 mod message_create;
 mod message_delete;
@@ -36,7 +40,10 @@ mod user_update;
 mod voice_server_update;
 mod voice_state_update;
 
+mod webhooks_update;
+
 mod ready;
+mod resumed;
 
 // Re-exports
 // CHANNEL
@@ -61,6 +68,10 @@ pub use guild_role_delete::GuildRoleDelete;
 pub use guild_role_update::GuildRoleUpdate;
 pub use guild_update::GuildUpdate;
 
+// INVITE
+pub use invite_create::InviteCreate;
+pub use invite_delete::InviteDelete;
+
 // MESSAGE
 pub use message_create::MessageCreate;
 pub use message_delete::MessageDelete;
@@ -80,13 +91,18 @@ pub use user_update::UserUpdate;
 pub use voice_server_update::VoiceServerUpdate;
 pub use voice_state_update::VoiceStateUpdate;
 
+// WEBHOOKS
+pub use webhooks_update::WebhooksUpdate;
+
 // READY
 pub use ready::Ready;
+pub use resumed::Resumed;
 
 // crate
 use super::payload::{Opcode, Payload};
 use crate::error::{PandaError, Result};
 
+use serde::Serialize;
 use serde_json::Value;
 use std::convert::TryFrom;
 
@@ -107,10 +123,16 @@ pub(crate) enum Event {
     Close(PandaError),
 }
 
-#[derive(Debug)]
-pub(crate) enum DispatchEvent {
+/// All events dispatched by the gateway, it's received by [`Client::on_event`]. It serializes
+/// in the same format as Discord sends it, `{"t": "EVENT_NAME", "d": event}`.
+///
+/// [`Client::on_event`]: ../../../client/struct.Client.html#method.on_event
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "t", content = "d", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DispatchEvent {
     Ready(Ready),
-    Resumed,
+    Resumed(Resumed),
     Reconnect,
     ChannelCreate(ChannelCreate),
     ChannelUpdate(ChannelUpdate),
@@ -133,6 +155,10 @@ pub(crate) enum DispatchEvent {
     GuildRoleUpdate(GuildRoleUpdate),
     GuildRoleDelete(GuildRoleDelete),
 
+    // invite
+    InviteCreate(InviteCreate),
+    InviteDelete(InviteDelete),
+
     // message
     MessageCreate(MessageCreate),
     MessageUpdate(MessageUpdate),
@@ -151,6 +177,118 @@ pub(crate) enum DispatchEvent {
     // voice
     VoiceStateUpdate(VoiceStateUpdate),
     VoiceServerUpdate(VoiceServerUpdate),
+
+    // webhooks
+    WebhooksUpdate(WebhooksUpdate),
+}
+
+impl DispatchEvent {
+    /// Returns the Discord name of the event, like `MESSAGE_CREATE`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ready(_) => "READY",
+            Self::Resumed(_) => "RESUMED",
+            Self::Reconnect => "RECONNECT",
+            Self::ChannelCreate(_) => "CHANNEL_CREATE",
+            Self::ChannelUpdate(_) => "CHANNEL_UPDATE",
+            Self::ChannelDelete(_) => "CHANNEL_DELETE",
+            Self::ChannelPinsUpdate(_) => "CHANNEL_PINS_UPDATE",
+            Self::GuildCreate(_) => "GUILD_CREATE",
+            Self::GuildUpdate(_) => "GUILD_UPDATE",
+            Self::GuildDelete(_) => "GUILD_DELETE",
+            Self::GuildBanAdd(_) => "GUILD_BAN_ADD",
+            Self::GuildBanRemove(_) => "GUILD_BAN_REMOVE",
+            Self::GuildEmojisUpdate(_) => "GUILD_EMOJIS_UPDATE",
+            Self::GuildIntegrationsUpdate(_) => "GUILD_INTEGRATIONS_UPDATE",
+            Self::GuildMemberAdd(_) => "GUILD_MEMBER_ADD",
+            Self::GuildMemberRemove(_) => "GUILD_MEMBER_REMOVE",
+            Self::GuildMemberUpdate(_) => "GUILD_MEMBER_UPDATE",
+            Self::GuildMembersChunk(_) => "GUILD_MEMBERS_CHUNK",
+            Self::GuildRoleCreate(_) => "GUILD_ROLE_CREATE",
+            Self::GuildRoleUpdate(_) => "GUILD_ROLE_UPDATE",
+            Self::GuildRoleDelete(_) => "GUILD_ROLE_DELETE",
+            Self::InviteCreate(_) => "INVITE_CREATE",
+            Self::InviteDelete(_) => "INVITE_DELETE",
+            Self::MessageCreate(_) => "MESSAGE_CREATE",
+            Self::MessageUpdate(_) => "MESSAGE_UPDATE",
+            Self::MessageDelete(_) => "MESSAGE_DELETE",
+            Self::MessageDeleteBulk(_) => "MESSAGE_DELETE_BULK",
+            Self::MessageReactionAdd(_) => "MESSAGE_REACTION_ADD",
+            Self::MessageReactionRemove(_) => "MESSAGE_REACTION_REMOVE",
+            Self::MessageReactionRemoveAll(_) => "MESSAGE_REACTION_REMOVE_ALL",
+            Self::MessageReactionRemoveEmoji(_) => "MESSAGE_REACTION_REMOVE_EMOJI",
+            Self::PresenceUpdate(_) => "PRESENCE_UPDATE",
+            Self::TypingStart(_) => "TYPING_START",
+            Self::UserUpdate(_) => "USER_UPDATE",
+            Self::VoiceStateUpdate(_) => "VOICE_STATE_UPDATE",
+            Self::VoiceServerUpdate(_) => "VOICE_SERVER_UPDATE",
+            Self::WebhooksUpdate(_) => "WEBHOOKS_UPDATE",
+        }
+    }
+
+    /// Returns the ID of the guild related to the event, if there is one
+    pub fn guild_id(&self) -> Option<&str> {
+        match self {
+            Self::ChannelCreate(e) => e.guild_id.as_deref(),
+            Self::ChannelUpdate(e) => e.guild_id.as_deref(),
+            Self::ChannelDelete(e) => e.guild_id.as_deref(),
+            Self::ChannelPinsUpdate(e) => e.guild_id.as_deref(),
+            Self::GuildCreate(e) => Some(&e.id),
+            Self::GuildUpdate(e) => Some(&e.id),
+            Self::GuildDelete(e) => Some(&e.id),
+            Self::GuildBanAdd(e) => Some(&e.guild_id),
+            Self::GuildBanRemove(e) => Some(&e.guild_id),
+            Self::GuildEmojisUpdate(e) => Some(&e.guild_id),
+            Self::GuildIntegrationsUpdate(e) => Some(&e.guild_id),
+            Self::GuildMemberAdd(e) => e.guild_id.as_deref(),
+            Self::GuildMemberRemove(e) => Some(&e.guild_id),
+            Self::GuildMemberUpdate(e) => Some(&e.guild_id),
+            Self::GuildMembersChunk(e) => Some(&e.guild_id),
+            Self::GuildRoleCreate(e) => Some(&e.guild_id),
+            Self::GuildRoleUpdate(e) => Some(&e.guild_id),
+            Self::GuildRoleDelete(e) => Some(&e.guild_id),
+            Self::InviteCreate(e) => e.guild_id.as_deref(),
+            Self::InviteDelete(e) => e.guild_id.as_deref(),
+            Self::MessageCreate(e) => e.guild_id.as_deref(),
+            Self::MessageUpdate(e) => e.guild_id.as_deref(),
+            Self::MessageDelete(e) => e.guild_id.as_deref(),
+            Self::MessageDeleteBulk(e) => e.guild_id.as_deref(),
+            Self::MessageReactionAdd(e) => e.guild_id.as_deref(),
+            Self::MessageReactionRemove(e) => e.guild_id.as_deref(),
+            Self::MessageReactionRemoveAll(e) => e.guild_id.as_deref(),
+            Self::MessageReactionRemoveEmoji(e) => e.guild_id.as_deref(),
+            Self::PresenceUpdate(e) => Some(&e.guild_id),
+            Self::TypingStart(e) => e.guild_id.as_deref(),
+            Self::VoiceStateUpdate(e) => e.guild_id.as_deref(),
+            Self::VoiceServerUpdate(e) => Some(&e.guild_id),
+            Self::WebhooksUpdate(e) => Some(&e.guild_id),
+            Self::Ready(_) | Self::Resumed(_) | Self::Reconnect | Self::UserUpdate(_) => None,
+        }
+    }
+
+    /// Returns the ID of the channel related to the event, if there is one
+    pub fn channel_id(&self) -> Option<&str> {
+        match self {
+            Self::ChannelCreate(e) => Some(&e.id),
+            Self::ChannelUpdate(e) => Some(&e.id),
+            Self::ChannelDelete(e) => Some(&e.id),
+            Self::ChannelPinsUpdate(e) => Some(&e.channel_id),
+            Self::InviteCreate(e) => Some(&e.channel_id),
+            Self::InviteDelete(e) => Some(&e.channel_id),
+            Self::MessageCreate(e) => Some(&e.channel_id),
+            Self::MessageUpdate(e) => Some(&e.channel_id),
+            Self::MessageDelete(e) => Some(&e.channel_id),
+            Self::MessageDeleteBulk(e) => Some(&e.channel_id),
+            Self::MessageReactionAdd(e) => Some(&e.channel_id),
+            Self::MessageReactionRemove(e) => Some(&e.channel_id),
+            Self::MessageReactionRemoveAll(e) => Some(&e.channel_id),
+            Self::MessageReactionRemoveEmoji(e) => Some(&e.channel_id),
+            Self::TypingStart(e) => Some(&e.channel_id),
+            Self::VoiceStateUpdate(e) => e.channel_id.as_deref(),
+            Self::WebhooksUpdate(e) => Some(&e.channel_id),
+            _ => None,
+        }
+    }
 }
 
 impl TryFrom<Payload> for Event {
@@ -196,7 +334,10 @@ fn handle_dispatch(p: Payload) -> Result<DispatchEvent> {
             let event = parse_dispatch!(d, "READY")?;
             Ok(DispatchEvent::Ready(event))
         }
-        "RESUMED" => Ok(DispatchEvent::Resumed),
+        "RESUMED" => {
+            let event = parse_dispatch!(d, "RESUMED")?;
+            Ok(DispatchEvent::Resumed(event))
+        }
         "RECONNECT" => Ok(DispatchEvent::Reconnect),
         // Channel
         "CHANNEL_CREATE" => {
@@ -257,8 +398,8 @@ fn handle_dispatch(p: Payload) -> Result<DispatchEvent> {
             let event = parse_dispatch!(d, "GUILD_MEMBER_REMOVE")?;
             Ok(DispatchEvent::GuildMemberRemove(event))
         }
-        "GUILD_MEMBER_CHUNK" => {
-            let event = parse_dispatch!(d, "GUILD_MEMBER_CHUNK")?;
+        "GUILD_MEMBERS_CHUNK" => {
+            let event = parse_dispatch!(d, "GUILD_MEMBERS_CHUNK")?;
             Ok(DispatchEvent::GuildMembersChunk(event))
         }
         "GUILD_ROLE_CREATE" => {
@@ -274,6 +415,16 @@ fn handle_dispatch(p: Payload) -> Result<DispatchEvent> {
             Ok(DispatchEvent::GuildRoleDelete(event))
         }
 
+        // Invite
+        "INVITE_CREATE" => {
+            let event = parse_dispatch!(d, "INVITE_CREATE")?;
+            Ok(DispatchEvent::InviteCreate(event))
+        }
+        "INVITE_DELETE" => {
+            let event = parse_dispatch!(d, "INVITE_DELETE")?;
+            Ok(DispatchEvent::InviteDelete(event))
+        }
+
         // Message
         "MESSAGE_CREATE" => {
             let event = parse_dispatch!(d, "MESSAGE_CREATE")?;
@@ -331,6 +482,12 @@ fn handle_dispatch(p: Payload) -> Result<DispatchEvent> {
             let event = parse_dispatch!(d, "VOICE_SERVER_UPDATE")?;
             Ok(DispatchEvent::VoiceServerUpdate(event))
         }
+
+        // Webhooks
+        "WEBHOOKS_UPDATE" => {
+            let event = parse_dispatch!(d, "WEBHOOKS_UPDATE")?;
+            Ok(DispatchEvent::WebhooksUpdate(event))
+        }
         _ => Err(PandaError::InvalidPayloadFormat("Unkown D event")),
     }
 }
@@ -1,11 +1,14 @@
-use crate::models::user::*;
+use crate::models::user::{
+    presence::{deserialize_user_id, serialize_user_id},
+    *,
+};
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PresenceUpdate {
     #[serde(rename = "user")]
-    #[serde(deserialize_with = "deserialize_user_id")]
+    #[serde(deserialize_with = "deserialize_user_id", serialize_with = "serialize_user_id")]
     pub user_id: String,
     pub roles: Vec<String>,
     pub game: Option<Activity>,
@@ -14,17 +17,3 @@ pub struct PresenceUpdate {
     pub activities: Vec<Activity>,
     //client_status: ClientStatus
 }
-
-// We use this function because discord not always send a full user,
-// but always send the user id.
-fn deserialize_user_id<'de, D>(deserializer: D) -> Result<String, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    #[derive(Deserialize)]
-    struct User {
-        id: String,
-    }
-
-    Deserialize::deserialize(deserializer).map(|user: User| user.id)
-}
@@ -8,8 +8,10 @@ pub struct Ready {
     pub v: u8,
     pub user: User,
     // pub private_channels: Option<Vec<()>>,
-    #[serde(skip_deserializing)]
-    pub guilds: Vec<Guild>,
+    /// The guilds of the user, they will be received later within [`GuildCreate`] events
+    ///
+    /// [`GuildCreate`]: struct.GuildCreate.html
+    pub guilds: Vec<UnavailableGuild>,
 
     pub session_id: String,
     pub shard: Option<[u64; 2]>,
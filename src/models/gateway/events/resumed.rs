@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Resumed {
+    /// Debug information of the gateway servers used in the connection
+    #[serde(default, rename = "_trace")]
+    pub trace: Vec<String>,
+}
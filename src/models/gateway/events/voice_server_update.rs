@@ -1,8 +1,9 @@
-use serde::{ Deserialize, Serialize };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct VoiceServerUpdate {
-    token: String,
-    guild_id: String,
-    endpoint: String
-}
\ No newline at end of file
+    pub token: String,
+    pub guild_id: String,
+    /// `None` when the voice server is not available
+    pub endpoint: Option<String>,
+}
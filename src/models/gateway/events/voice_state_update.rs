@@ -1,5 +1,15 @@
 use crate::models::voice::*;
-use serde::{ Deserialize, Serialize };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct VoiceStateUpdate(pub VoiceState);
\ No newline at end of file
+use std::ops::Deref;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VoiceStateUpdate(pub VoiceState);
+
+impl Deref for VoiceStateUpdate {
+    type Target = VoiceState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhooksUpdate {
+    pub guild_id: String,
+    pub channel_id: String,
+}
@@ -0,0 +1,91 @@
+use crate::models::{user::User, webhook::Webhook};
+use serde::{Deserialize, Serialize};
+use serde_repr::*;
+
+/// A page of the audit log of a guild, with the users and webhooks referenced by its entries
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditLog {
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(default)]
+    pub audit_log_entries: Vec<AuditLogEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditLogEntry {
+    pub id: String,
+    /// ID of the affected entity (webhook, user, role, etc.)
+    pub target_id: Option<String>,
+    #[serde(default)]
+    pub changes: Vec<AuditLogChange>,
+    /// The user who made the changes
+    pub user_id: String,
+    pub action_type: AuditLogEvent,
+    pub options: Option<AuditEntryInfo>,
+    pub reason: Option<String>,
+}
+
+/// A changed value, its type depends on the `key`, see the
+/// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-change-object-audit-log-change-key)
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditLogChange {
+    pub key: String,
+    pub new_value: Option<serde_json::Value>,
+    pub old_value: Option<serde_json::Value>,
+}
+
+/// Additional info of some kinds of entries
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditEntryInfo {
+    pub delete_member_days: Option<String>,
+    pub members_removed: Option<String>,
+    pub channel_id: Option<String>,
+    pub message_id: Option<String>,
+    pub count: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub role_name: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize_repr, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum AuditLogEvent {
+    GuildUpdate = 1,
+    ChannelCreate = 10,
+    ChannelUpdate = 11,
+    ChannelDelete = 12,
+    ChannelOverwriteCreate = 13,
+    ChannelOverwriteUpdate = 14,
+    ChannelOverwriteDelete = 15,
+    MemberKick = 20,
+    MemberPrune = 21,
+    MemberBanAdd = 22,
+    MemberBanRemove = 23,
+    MemberUpdate = 24,
+    MemberRoleUpdate = 25,
+    MemberMove = 26,
+    MemberDisconnect = 27,
+    BotAdd = 28,
+    RoleCreate = 30,
+    RoleUpdate = 31,
+    RoleDelete = 32,
+    InviteCreate = 40,
+    InviteUpdate = 41,
+    InviteDelete = 42,
+    WebhookCreate = 50,
+    WebhookUpdate = 51,
+    WebhookDelete = 52,
+    EmojiCreate = 60,
+    EmojiUpdate = 61,
+    EmojiDelete = 62,
+    MessageDelete = 72,
+    MessageBulkDelete = 73,
+    MessagePin = 74,
+    MessageUnpin = 75,
+    IntegrationCreate = 80,
+    IntegrationUpdate = 81,
+    IntegrationDelete = 82,
+}
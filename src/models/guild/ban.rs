@@ -0,0 +1,9 @@
+use crate::models::user::User;
+use serde::{Deserialize, Serialize};
+
+/// A banned user of a guild
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Ban {
+    pub reason: Option<String>,
+    pub user: User,
+}
@@ -1,11 +1,21 @@
+mod audit_log;
+mod ban;
 mod member;
+mod partial;
+mod preview;
 mod role;
+mod widget;
 
-use crate::models::{channel::Channel, emoji::Emoji};
+use crate::models::{channel::Channel, emoji::Emoji, user::Presence, voice::VoiceState};
 use serde::{Deserialize, Serialize};
 
+pub use audit_log::{AuditEntryInfo, AuditLog, AuditLogChange, AuditLogEntry, AuditLogEvent};
+pub use ban::Ban;
 pub use member::Member as GuildMember;
+pub use partial::PartialGuild;
+pub use preview::GuildPreview;
 pub use role::Role;
+pub use widget::{GuildWidget, VanityUrl};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Guild {
@@ -41,12 +51,29 @@ pub struct Guild {
     pub premium_subscription_count: Option<u64>,
     pub preferred_locale: String,
     // elds are only sent within GUILD_CREATE event
+    #[serde(default)]
     pub joined_at: String,
     pub large: Option<bool>,
+    #[serde(default)]
     pub unavailable: bool,
     pub member_count: Option<u64>,
-    // ce_states: Vec<Voice>,
+    #[serde(default)]
+    pub voice_states: Vec<VoiceState>,
+    #[serde(default)]
     pub members: Vec<GuildMember>,
+    #[serde(default)]
     pub channels: Vec<Channel>,
-    // pub presences: Vec<Presence>,
+    #[serde(default)]
+    pub presences: Vec<Presence>,
+}
+
+/// A guild that is not available, because of an outage or because it has not been received yet.
+/// It's sent within [`Ready`] event.
+///
+/// [`Ready`]: ../gateway/events/struct.Ready.html
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UnavailableGuild {
+    pub id: String,
+    #[serde(default)]
+    pub unavailable: bool,
 }
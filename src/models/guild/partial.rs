@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Partial guild returned when the guilds of the current user are listed
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PartialGuild {
+    pub id: String,
+    pub name: String,
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub owner: bool,
+    pub permissions: u64,
+    #[serde(default)]
+    pub features: Vec<String>,
+}
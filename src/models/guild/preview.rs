@@ -0,0 +1,17 @@
+use crate::models::emoji::Emoji;
+use serde::{Deserialize, Serialize};
+
+/// Preview of a public guild, it can be fetched even if the user is not in the guild
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GuildPreview {
+    pub id: String,
+    pub name: String,
+    pub icon: Option<String>,
+    pub splash: Option<String>,
+    pub discovery_splash: Option<String>,
+    pub emojis: Vec<Emoji>,
+    pub features: Vec<String>,
+    pub approximate_member_count: u64,
+    pub approximate_presence_count: u64,
+    pub description: Option<String>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Settings of the widget of a guild
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GuildWidget {
+    pub enabled: bool,
+    pub channel_id: Option<String>,
+}
+
+/// Vanity invite of a guild, only guilds with the `VANITY_URL` feature have a code
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct VanityUrl {
+    pub code: Option<String>,
+    #[serde(default)]
+    pub uses: u64,
+}
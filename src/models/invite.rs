@@ -1,14 +1,38 @@
-use super::{Guild, Channel, User};
+use super::{Channel, User};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Invite {
     pub code: String,
-    pub guild: Option<Guild>,
+    pub guild: Option<InviteGuild>,
     pub channel: Channel,
     pub inviter: Option<User>,
     pub target_user: Option<User>,
     // target_user_type always 1
     pub approximate_presence_count: Option<u64>,
-    pub approximate_member_count: u64
-}
\ No newline at end of file
+    pub approximate_member_count: Option<u64>,
+
+    // Metadata, only sent when the invites of a channel are fetched or an invite is created
+    pub uses: Option<u64>,
+    pub max_uses: Option<u64>,
+    pub max_age: Option<u64>,
+    pub temporary: Option<bool>,
+    pub created_at: Option<String>,
+}
+
+/// Partial guild sent within an [`Invite`]
+///
+/// [`Invite`]: struct.Invite.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InviteGuild {
+    pub id: String,
+    pub name: String,
+    pub icon: Option<String>,
+    pub splash: Option<String>,
+    pub banner: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    pub verification_level: Option<u64>,
+    pub vanity_url_code: Option<String>,
+}
@@ -4,7 +4,7 @@ use serde_repr::*;
 
 use std::default::Default;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct Activity {
     pub name: String,
     #[serde(rename = "type")]
@@ -22,7 +22,7 @@ pub struct Activity {
     pub flags: Option<u64>,
 }
 
-#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq)]
+#[derive(Clone, Debug, Deserialize_repr, Serialize_repr, PartialEq)]
 #[repr(u8)]
 pub enum ActivityKind {
     Game = 0,
@@ -1,12 +1,16 @@
 //! User related models
 
 mod activity;
+pub(crate) mod presence;
 mod status_update;
 
 pub use activity::{Activity, ActivityKind};
+pub use presence::Presence;
 pub use status_update::{Status, StatusUpdate};
 
+use crate::{error::Result, http::HttpClient, models::channel::Message};
 use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct User {
     pub id: String,
@@ -21,3 +25,14 @@ pub struct User {
     pub flags: Option<u64>,
     pub premium_type: Option<u64>,
 }
+
+impl User {
+    /// Send a direct message to the user, the DM channel is opened with [`HttpClient.create_dm`]
+    ///
+    /// [`HttpClient.create_dm`]: ../../../struct.HttpClient.html#method.create_dm
+    pub async fn dm(&self, http: &HttpClient, content: impl AsRef<str>) -> Result<Message> {
+        let channel = http.create_dm(&self.id).await?;
+
+        http.send_message(&channel.id, content).await
+    }
+}
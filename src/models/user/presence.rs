@@ -0,0 +1,65 @@
+use super::Activity;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Presence of an [`User`], sent within [`GuildMembersChunk`] and [`GuildCreate`] events
+///
+/// [`User`]: struct.User.html
+/// [`GuildMembersChunk`]: ../gateway/events/struct.GuildMembersChunk.html
+/// [`GuildCreate`]: ../gateway/events/struct.GuildCreate.html
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Presence {
+    #[serde(rename = "user")]
+    #[serde(deserialize_with = "deserialize_user_id", serialize_with = "serialize_user_id")]
+    pub user_id: String,
+    pub game: Option<Activity>,
+    pub status: String,
+    #[serde(default)]
+    pub activities: Vec<Activity>,
+}
+
+// We use this function because discord not always send a full user,
+// but always send the user id.
+pub(crate) fn deserialize_user_id<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct User {
+        id: String,
+    }
+
+    Deserialize::deserialize(deserializer).map(|user: User| user.id)
+}
+
+// Serialized as a partial user, the same format that is deserialized
+pub(crate) fn serialize_user_id<S>(user_id: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct User<'a> {
+        id: &'a str,
+    }
+
+    User { id: user_id }.serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn user_id_round_trip() {
+        let json = json!({"user": {"id": "1", "username": "panda"}, "game": null, "status": "online"});
+        let presence: Presence = serde_json::from_value(json).unwrap();
+        assert_eq!(presence.user_id, "1");
+
+        let serialized = serde_json::to_value(&presence).unwrap();
+        assert_eq!(serialized["user"], json!({"id": "1"}));
+
+        let deserialized: Presence = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, presence);
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct StatusUpdate {
     since: Option<u64>,
     game: Option<Activity>,
@@ -3,10 +3,11 @@
 use crate::models::guild::GuildMember;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct VoiceState {
     pub guild_id: Option<String>,
-    pub channel_id: String,
+    /// `None` when the user left the voice channel
+    pub channel_id: Option<String>,
     pub user_id: String,
     pub member: Option<GuildMember>,
     pub session_id: String,
@@ -15,5 +16,16 @@ pub struct VoiceState {
     pub self_deaf: bool,
     pub self_mute: bool,
     pub self_stream: Option<bool>,
-    pub supress: bool,
+    pub suppress: bool,
+}
+
+/// A voice region that can be used when creating servers or modifying the region of a guild
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct VoiceRegion {
+    pub id: String,
+    pub name: String,
+    pub vip: bool,
+    pub optimal: bool,
+    pub deprecated: bool,
+    pub custom: bool,
 }
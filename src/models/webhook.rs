@@ -0,0 +1,27 @@
+use super::User;
+use serde::{Deserialize, Serialize};
+use serde_repr::*;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Webhook {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: WebhookKind,
+    pub guild_id: Option<String>,
+    pub channel_id: String,
+    /// The user that created the webhook, not sent when the webhook is fetched with its token
+    pub user: Option<User>,
+    pub name: Option<String>,
+    pub avatar: Option<String>,
+    /// The secure token of the webhook, only sent for incoming webhooks
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize_repr, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum WebhookKind {
+    /// Webhook that can post messages to a channel with its token
+    Incoming = 1,
+    /// Internal webhook used to post messages of followed channels
+    ChannelFollower = 2,
+}
@@ -5,7 +5,8 @@ pub(crate) mod websocket;
 use delay::Delay;
 use join_handle::JoinHandle;
 
-use std::{future::Future, time::Duration};
+use crate::error::Result;
+use std::{future::Future, path::Path, time::Duration};
 
 pub(crate) fn spawn<F>(future: F) -> JoinHandle<F::Output>
 where
@@ -19,6 +20,19 @@ where
     return JoinHandle::AsyncStd(async_std::task::spawn(future));
 }
 
+/// Run a blocking function in a thread where blocking is allowed
+pub(crate) fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    #[cfg(feature = "tokio-runtime")]
+    return JoinHandle::Tokio(tokio::task::spawn_blocking(f));
+
+    #[cfg(feature = "async-std-runtime" /*, feature = "async-std-native-tls"*/)]
+    return JoinHandle::AsyncStd(async_std::task::spawn_blocking(f));
+}
+
 pub(crate) fn sleep(duration: Duration) -> delay::Delay {
     #[cfg(feature = "tokio-runtime")]
     return Delay::Tokio(tokio::time::delay_for(duration));
@@ -26,3 +40,27 @@ pub(crate) fn sleep(duration: Duration) -> delay::Delay {
     #[cfg(feature = "async-std-runtime" /*, feature = "async-std-native-tls"*/)]
     return Delay::AsyncStd(Box::pin(async_std::task::sleep(duration)));
 }
+
+pub(crate) async fn read_file(path: impl AsRef<Path>) -> Result<Vec<u8>> {
+    #[cfg(feature = "tokio-runtime")]
+    return Ok(tokio::fs::read(path.as_ref()).await?);
+
+    #[cfg(feature = "async-std-runtime" /*, feature = "async-std-native-tls"*/)]
+    return Ok(async_std::fs::read(path.as_ref()).await?);
+}
+
+pub(crate) async fn write_file(path: impl AsRef<Path>, content: &[u8]) -> Result<()> {
+    #[cfg(feature = "tokio-runtime")]
+    return Ok(tokio::fs::write(path.as_ref(), content).await?);
+
+    #[cfg(feature = "async-std-runtime" /*, feature = "async-std-native-tls"*/)]
+    return Ok(async_std::fs::write(path.as_ref(), content).await?);
+}
+
+pub(crate) async fn rename(from: impl AsRef<Path>, to: impl AsRef<Path>) -> Result<()> {
+    #[cfg(feature = "tokio-runtime")]
+    return Ok(tokio::fs::rename(from.as_ref(), to.as_ref()).await?);
+
+    #[cfg(feature = "async-std-runtime" /*, feature = "async-std-native-tls"*/)]
+    return Ok(async_std::fs::rename(from.as_ref(), to.as_ref()).await?);
+}
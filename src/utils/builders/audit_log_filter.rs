@@ -0,0 +1,69 @@
+use crate::models::guild::AuditLogEvent;
+use url::form_urlencoded::Serializer;
+
+/// Filters of the audit log, by default it returns the last 50 entries. To get the next page
+/// use the ID of the last received entry with `before()`.
+#[derive(Debug, Default)]
+pub struct AuditLogFilter {
+    user_id: Option<String>,
+    action_type: Option<AuditLogEvent>,
+    before: Option<String>,
+    limit: Option<u8>,
+}
+
+impl AuditLogFilter {
+    pub fn new() -> Self {
+        AuditLogFilter::default()
+    }
+
+    /// Only the entries of the changes made by this user
+    pub fn user(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+
+        self
+    }
+
+    /// Only the entries of this kind
+    pub fn action_type(mut self, action_type: AuditLogEvent) -> Self {
+        self.action_type = Some(action_type);
+
+        self
+    }
+
+    /// Only the entries older than this entry
+    pub fn before(mut self, entry_id: impl Into<String>) -> Self {
+        self.before = Some(entry_id.into());
+
+        self
+    }
+
+    /// Max number of entries, between 1 and 100
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Returns the query string of the filter
+    pub(crate) fn query(&self) -> String {
+        let mut query = Serializer::new(String::new());
+
+        if let Some(user_id) = &self.user_id {
+            query.append_pair("user_id", user_id);
+        }
+
+        if let Some(action_type) = self.action_type {
+            query.append_pair("action_type", &(action_type as u8).to_string());
+        }
+
+        if let Some(before) = &self.before {
+            query.append_pair("before", before);
+        }
+
+        if let Some(limit) = self.limit {
+            query.append_pair("limit", &limit.to_string());
+        }
+
+        query.finish()
+    }
+}
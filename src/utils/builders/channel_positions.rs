@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// List of the new positions of the channels of a guild, only the moved channels are needed
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ChannelPositions {
+    positions: Vec<ChannelPosition>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ChannelPosition {
+    id: String,
+    position: u64,
+}
+
+impl ChannelPositions {
+    pub fn new() -> Self {
+        ChannelPositions::default()
+    }
+
+    pub fn channel(mut self, channel_id: impl Into<String>, position: u64) -> Self {
+        self.positions.push(ChannelPosition {
+            id: channel_id.into(),
+            position,
+        });
+
+        self
+    }
+}
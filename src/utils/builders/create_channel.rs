@@ -0,0 +1,97 @@
+use crate::models::channel::{ChannelKind, Overwrite};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateChannel {
+    name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<ChannelKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bitrate: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    permission_overwrites: Vec<Overwrite>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+}
+
+impl CreateChannel {
+    /// Create a text channel with the given name
+    pub fn new(name: impl Into<String>) -> Self {
+        CreateChannel {
+            name: name.into(),
+            kind: None,
+            topic: None,
+            bitrate: None,
+            user_limit: None,
+            rate_limit_per_user: None,
+            position: None,
+            permission_overwrites: Vec::new(),
+            parent_id: None,
+            nsfw: None,
+        }
+    }
+
+    pub fn kind(mut self, kind: ChannelKind) -> Self {
+        self.kind = Some(kind);
+
+        self
+    }
+
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Some(topic.into());
+
+        self
+    }
+
+    pub fn bitrate(mut self, bitrate: u64) -> Self {
+        self.bitrate = Some(bitrate);
+
+        self
+    }
+
+    pub fn user_limit(mut self, user_limit: u64) -> Self {
+        self.user_limit = Some(user_limit);
+
+        self
+    }
+
+    pub fn rate_limit_per_user(mut self, rate_limit_per_user: u64) -> Self {
+        self.rate_limit_per_user = Some(rate_limit_per_user);
+
+        self
+    }
+
+    pub fn position(mut self, position: u64) -> Self {
+        self.position = Some(position);
+
+        self
+    }
+
+    pub fn permission_overwrite(mut self, permission_overwrite: Overwrite) -> Self {
+        self.permission_overwrites.push(permission_overwrite);
+
+        self
+    }
+
+    pub fn parent_id(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_id = Some(parent_id.into());
+
+        self
+    }
+
+    pub fn is_nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+
+        self
+    }
+}
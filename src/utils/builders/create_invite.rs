@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvite {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_age: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_uses: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temporary: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unique: Option<bool>,
+}
+
+impl CreateInvite {
+    pub fn new() -> Self {
+        CreateInvite::default()
+    }
+
+    /// Duration of the invite in seconds, 0 for never. Defaults to 86400 (24 hours)
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        self.max_age = Some(max_age);
+
+        self
+    }
+
+    /// Max number of uses, 0 for unlimited. Defaults to 0
+    pub fn max_uses(mut self, max_uses: u64) -> Self {
+        self.max_uses = Some(max_uses);
+
+        self
+    }
+
+    /// Grant temporary membership, members are kicked when they disconnect unless they get a role
+    pub fn temporary(mut self, temporary: bool) -> Self {
+        self.temporary = Some(temporary);
+
+        self
+    }
+
+    /// Don't reuse a similar invite, useful for creating many unique one time use invites
+    pub fn unique(mut self, unique: bool) -> Self {
+        self.unique = Some(unique);
+
+        self
+    }
+}
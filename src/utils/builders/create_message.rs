@@ -0,0 +1,83 @@
+use crate::{
+    http::AttachmentFile,
+    models::channel::{AllowedMentions, Embed, Message, MessageReference},
+};
+use serde::{Deserialize, Serialize};
+
+/// The body of a new message, it needs a content, an embed or at least one file
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embed: Option<Embed>,
+    tts: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_reference: Option<MessageReference>,
+    // Sent as multipart/form-data, not in the JSON body
+    #[serde(skip)]
+    pub(crate) files: Vec<AttachmentFile>,
+}
+
+impl CreateMessage {
+    pub fn new() -> Self {
+        CreateMessage::default()
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+
+        self
+    }
+
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embed = Some(embed);
+
+        self
+    }
+
+    /// Send the message as text-to-speech
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = tts;
+
+        self
+    }
+
+    /// Used to verify that the message was sent, it's included in the [`MessageCreate`] event
+    ///
+    /// [`MessageCreate`]: ../../models/gateway/events/struct.MessageCreate.html
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+
+        self
+    }
+
+    /// Set which mentions of the content will notify the users, it overrides the default of the client
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+
+        self
+    }
+
+    /// Reply to a message
+    pub fn reference(mut self, message: &Message) -> Self {
+        self.message_reference = Some(MessageReference {
+            id: Some(message.id.clone()),
+            channel_id: Some(message.channel_id.clone()),
+            guild_id: message.guild_id.clone(),
+        });
+
+        self
+    }
+
+    /// Add a file to the message, it can be called multiple times
+    pub fn file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EmojiEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roles: Option<Vec<String>>,
+}
+
+impl EmojiEdit {
+    pub fn new() -> Self {
+        EmojiEdit::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Restrict the emoji to a role, it can be called multiple times
+    pub fn role(mut self, role_id: impl Into<String>) -> Self {
+        self.roles.get_or_insert_with(Vec::new).push(role_id.into());
+
+        self
+    }
+
+    /// Remove the role restrictions, everyone will be able to use the emoji
+    pub fn clear_roles(mut self) -> Self {
+        self.roles = Some(Vec::new());
+
+        self
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct GuildEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification_level: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_message_notifications: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explicit_content_filter: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    afk_channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    afk_timeout: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    splash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    banner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preferred_locale: Option<String>,
+}
+
+impl GuildEdit {
+    pub fn new() -> Self {
+        GuildEdit::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+
+        self
+    }
+
+    pub fn verification_level(mut self, verification_level: u64) -> Self {
+        self.verification_level = Some(verification_level);
+
+        self
+    }
+
+    pub fn default_message_notifications(mut self, default_message_notifications: u64) -> Self {
+        self.default_message_notifications = Some(default_message_notifications);
+
+        self
+    }
+
+    pub fn explicit_content_filter(mut self, explicit_content_filter: u64) -> Self {
+        self.explicit_content_filter = Some(explicit_content_filter);
+
+        self
+    }
+
+    pub fn afk_channel_id(mut self, afk_channel_id: impl Into<String>) -> Self {
+        self.afk_channel_id = Some(afk_channel_id.into());
+
+        self
+    }
+
+    pub fn afk_timeout(mut self, afk_timeout: u64) -> Self {
+        self.afk_timeout = Some(afk_timeout);
+
+        self
+    }
+
+    /// Set the icon, it must be a base64 data URI like `data:image/png;base64,...`
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+
+        self
+    }
+
+    /// Transfer the ownership of the guild, the current user must be the owner
+    pub fn owner_id(mut self, owner_id: impl Into<String>) -> Self {
+        self.owner_id = Some(owner_id.into());
+
+        self
+    }
+
+    /// Set the splash, it must be a base64 data URI like `data:image/png;base64,...`
+    pub fn splash(mut self, splash: impl Into<String>) -> Self {
+        self.splash = Some(splash.into());
+
+        self
+    }
+
+    /// Set the banner, it must be a base64 data URI like `data:image/png;base64,...`
+    pub fn banner(mut self, banner: impl Into<String>) -> Self {
+        self.banner = Some(banner.into());
+
+        self
+    }
+
+    pub fn system_channel_id(mut self, system_channel_id: impl Into<String>) -> Self {
+        self.system_channel_id = Some(system_channel_id.into());
+
+        self
+    }
+
+    pub fn preferred_locale(mut self, preferred_locale: impl Into<String>) -> Self {
+        self.preferred_locale = Some(preferred_locale.into());
+
+        self
+    }
+}
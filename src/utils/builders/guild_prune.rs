@@ -0,0 +1,60 @@
+use url::form_urlencoded::Serializer;
+
+/// Options of a guild prune, by default it counts the members inactive for 7 days
+/// without roles
+#[derive(Debug)]
+pub struct GuildPrune {
+    days: u64,
+    compute_prune_count: bool,
+    include_roles: Vec<String>,
+}
+
+impl Default for GuildPrune {
+    fn default() -> Self {
+        GuildPrune {
+            days: 7,
+            compute_prune_count: true,
+            include_roles: Vec::new(),
+        }
+    }
+}
+
+impl GuildPrune {
+    pub fn new() -> Self {
+        GuildPrune::default()
+    }
+
+    /// Number of days of inactivity, between 1 and 30
+    pub fn days(mut self, days: u64) -> Self {
+        self.days = days;
+
+        self
+    }
+
+    /// Return the number of pruned members, it's recommended to disable it for large guilds
+    pub fn compute_prune_count(mut self, compute_prune_count: bool) -> Self {
+        self.compute_prune_count = compute_prune_count;
+
+        self
+    }
+
+    /// Also prune the members with this role
+    pub fn include_role(mut self, role_id: impl Into<String>) -> Self {
+        self.include_roles.push(role_id.into());
+
+        self
+    }
+
+    /// Returns the query string of the prune
+    pub(crate) fn query(&self) -> String {
+        let mut query = Serializer::new(String::new());
+        query.append_pair("days", &self.days.to_string());
+        query.append_pair("compute_prune_count", &self.compute_prune_count.to_string());
+
+        if !self.include_roles.is_empty() {
+            query.append_pair("include_roles", &self.include_roles.join(","));
+        }
+
+        query.finish()
+    }
+}
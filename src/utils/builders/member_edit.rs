@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct MemberEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roles: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mute: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deaf: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<String>,
+}
+
+impl MemberEdit {
+    pub fn new() -> Self {
+        MemberEdit::default()
+    }
+
+    /// Set the nickname, an empty string removes it. Requires the **MANAGE_NICKNAMES** permission
+    pub fn nick(mut self, nick: impl Into<String>) -> Self {
+        self.nick = Some(nick.into());
+
+        self
+    }
+
+    /// Replace all roles of the member. Requires the **MANAGE_ROLES** permission
+    pub fn roles(mut self, roles: Vec<String>) -> Self {
+        self.roles = Some(roles);
+
+        self
+    }
+
+    /// Requires the **MUTE_MEMBERS** permission
+    pub fn mute(mut self, mute: bool) -> Self {
+        self.mute = Some(mute);
+
+        self
+    }
+
+    /// Requires the **DEAFEN_MEMBERS** permission
+    pub fn deaf(mut self, deaf: bool) -> Self {
+        self.deaf = Some(deaf);
+
+        self
+    }
+
+    /// Move the member to other voice channel, only if the member is connected to voice.
+    /// Requires the **MOVE_MEMBERS** permission
+    pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+        self.channel_id = Some(channel_id.into());
+
+        self
+    }
+}
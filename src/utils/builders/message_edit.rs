@@ -1,8 +1,13 @@
+use crate::models::channel::{AllowedMentions, Embed};
 use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct MessageEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
-    embed: Option<()>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embed: Option<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
     flags: u64, // TODO: SEE FLAGS
 }
 
@@ -17,8 +22,17 @@ impl MessageEdit {
         self
     }
 
-    pub fn embed(self) {
-        unimplemented!()
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embed = Some(embed);
+
+        self
+    }
+
+    /// Set which mentions of the new content will be highlighted
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+
+        self
     }
 
     pub fn flags(self) {
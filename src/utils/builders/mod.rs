@@ -1,5 +1,35 @@
+mod audit_log_filter;
 mod channel_edit;
+mod channel_positions;
+mod create_channel;
+mod create_invite;
+mod create_message;
+mod emoji_edit;
+mod guild_edit;
+mod guild_prune;
+mod guild_widget_edit;
+mod member_edit;
 mod message_edit;
+mod role_edit;
+mod role_positions;
+mod user_edit;
+mod webhook_edit;
+mod webhook_message;
 
+pub use audit_log_filter::AuditLogFilter;
 pub use channel_edit::ChannelEdit;
+pub use channel_positions::ChannelPositions;
+pub use create_channel::CreateChannel;
+pub use create_invite::CreateInvite;
+pub use create_message::CreateMessage;
+pub use emoji_edit::EmojiEdit;
+pub use guild_edit::GuildEdit;
+pub use guild_prune::GuildPrune;
+pub use guild_widget_edit::GuildWidgetEdit;
+pub use member_edit::MemberEdit;
 pub use message_edit::MessageEdit;
+pub use role_edit::RoleEdit;
+pub use role_positions::RolePositions;
+pub use user_edit::UserEdit;
+pub use webhook_edit::WebhookEdit;
+pub use webhook_message::WebhookMessage;
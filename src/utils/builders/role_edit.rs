@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RoleEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hoist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mentionable: Option<bool>,
+}
+
+impl RoleEdit {
+    pub fn new() -> Self {
+        RoleEdit::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Set the permissions bitwise value
+    pub fn permissions(mut self, permissions: u64) -> Self {
+        self.permissions = Some(permissions);
+
+        self
+    }
+
+    /// Set the RGB color value, like `0xFF0000`
+    pub fn color(mut self, color: u64) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    /// Display the role members separately in the sidebar
+    pub fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Some(hoist);
+
+        self
+    }
+
+    pub fn mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = Some(mentionable);
+
+        self
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// List of the new positions of the roles of a guild, only the moved roles are needed
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct RolePositions {
+    positions: Vec<RolePosition>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct RolePosition {
+    id: String,
+    position: u64,
+}
+
+impl RolePositions {
+    pub fn new() -> Self {
+        RolePositions::default()
+    }
+
+    pub fn role(mut self, role_id: impl Into<String>, position: u64) -> Self {
+        self.positions.push(RolePosition {
+            id: role_id.into(),
+            position,
+        });
+
+        self
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct UserEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar: Option<String>,
+}
+
+impl UserEdit {
+    pub fn new() -> Self {
+        UserEdit::default()
+    }
+
+    /// Change the username, it may change the discriminator of the user
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+
+        self
+    }
+
+    /// Change the avatar, it must be an image encoded as a data URI like
+    /// `data:image/png;base64,BASE64_ENCODED_IMAGE`, see [`image_data_uri`]
+    ///
+    /// [`image_data_uri`]: ../fn.image_data_uri.html
+    pub fn avatar(mut self, avatar: impl Into<String>) -> Self {
+        self.avatar = Some(avatar.into());
+
+        self
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct WebhookEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<String>,
+}
+
+impl WebhookEdit {
+    pub fn new() -> Self {
+        WebhookEdit::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Change the default avatar, it must be an image encoded as a data URI, see [`image_data_uri`]
+    ///
+    /// [`image_data_uri`]: ../fn.image_data_uri.html
+    pub fn avatar(mut self, avatar: impl Into<String>) -> Self {
+        self.avatar = Some(avatar.into());
+
+        self
+    }
+
+    /// Move the webhook to another channel of the guild
+    pub fn channel(mut self, channel_id: impl Into<String>) -> Self {
+        self.channel_id = Some(channel_id.into());
+
+        self
+    }
+}
@@ -0,0 +1,65 @@
+use crate::models::channel::{AllowedMentions, Embed};
+use serde::{Deserialize, Serialize};
+
+/// The body of a message sent by a webhook, it needs a content or at least one embed
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct WebhookMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tts: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    embeds: Vec<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+}
+
+impl WebhookMessage {
+    pub fn new() -> Self {
+        WebhookMessage::default()
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+
+        self
+    }
+
+    /// Override the default username of the webhook, it's ignored when a message is edited
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+
+        self
+    }
+
+    /// Override the default avatar of the webhook, it's ignored when a message is edited
+    pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
+        self.avatar_url = Some(avatar_url.into());
+
+        self
+    }
+
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = Some(tts);
+
+        self
+    }
+
+    /// Add an embed to the message, up to 10 embeds
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+
+        self
+    }
+
+    /// Set which mentions of the content will notify the users
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+
+        self
+    }
+}
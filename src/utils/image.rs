@@ -0,0 +1,56 @@
+use crate::error::{PandaError, Result};
+
+/// Encode an image as a data URI, like `data:image/png;base64,BASE64_ENCODED_IMAGE`, the format
+/// used by Discord to upload emojis and avatars. The MIME type is detected from the first bytes
+/// of the image, only PNG, JPEG and GIF images are supported.
+pub fn image_data_uri(image: impl AsRef<[u8]>) -> Result<String> {
+    let image = image.as_ref();
+
+    let mime = if image.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if image.starts_with(b"\xff\xd8\xff") {
+        "image/jpeg"
+    } else if image.starts_with(b"GIF87a") || image.starts_with(b"GIF89a") {
+        "image/gif"
+    } else {
+        return Err(PandaError::UnsupportedImageFormat);
+    };
+
+    Ok(format!("data:{};base64,{}", mime, base64::encode(image)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mime_type_is_detected() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(
+            image_data_uri(&png[..]).unwrap(),
+            format!("data:image/png;base64,{}", base64::encode(&png[..]))
+        );
+
+        let jpeg = b"\xff\xd8\xff\xe0\0\x10JFIF";
+        assert!(image_data_uri(&jpeg[..])
+            .unwrap()
+            .starts_with("data:image/jpeg;base64,"));
+
+        assert!(image_data_uri("GIF87a...")
+            .unwrap()
+            .starts_with("data:image/gif;base64,"));
+        assert!(image_data_uri("GIF89a...")
+            .unwrap()
+            .starts_with("data:image/gif;base64,"));
+    }
+
+    #[test]
+    fn unknown_formats_are_rejected() {
+        for image in &[&b"RIFF\0\0\0\0WEBP"[..], b"", b"\x89PN"] {
+            match image_data_uri(image) {
+                Err(PandaError::UnsupportedImageFormat) => {}
+                other => panic!("Expected UnsupportedImageFormat, got {:?}", other),
+            }
+        }
+    }
+}
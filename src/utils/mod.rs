@@ -1 +1,6 @@
 pub mod builders;
+mod image;
+mod paginator;
+
+pub use image::image_data_uri;
+pub use paginator::Paginator;
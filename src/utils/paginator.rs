@@ -0,0 +1,227 @@
+use crate::{
+    client::SessionData,
+    error::Result,
+    models::{channel::Embed, gateway::events::MessageReactionAdd},
+    runtime,
+    utils::builders::MessageEdit,
+};
+
+use std::time::Duration;
+
+use futures::{select, stream::FusedStream, FutureExt, StreamExt};
+
+const FIRST: &str = "\u{23ee}\u{fe0f}";
+const PREVIOUS: &str = "\u{25c0}\u{fe0f}";
+const NEXT: &str = "\u{25b6}\u{fe0f}";
+const LAST: &str = "\u{23ed}\u{fe0f}";
+const STOP: &str = "\u{23f9}\u{fe0f}";
+
+/// Sends a list of [`Embed`] pages as a single message, that can be navigated
+/// with reactions by the user who invoked it.
+///
+/// ```ignore
+/// Paginator::new(pages)
+///     .timeout(Duration::from_secs(60))
+///     .run(&session, &msg.channel_id, &msg.author.id)
+///     .await?;
+/// ```
+///
+/// [`Embed`]: ../models/channel/struct.Embed.html
+pub struct Paginator {
+    pages: Vec<Embed>,
+    timeout: Duration,
+}
+
+impl Paginator {
+    /// Create a new paginator with the given pages, the default idle timeout is 2 minutes
+    pub fn new(pages: Vec<Embed>) -> Self {
+        Paginator {
+            pages,
+            timeout: Duration::from_secs(120),
+        }
+    }
+
+    /// Set the time without any reaction after which the paginator stops
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Send the first page to the channel, and handle the navigation reactions of
+    /// the given user until the idle timeout is reached or the user stops it.
+    /// When it finishes, all navigation reactions of the bot are removed.
+    pub async fn run<S>(
+        self,
+        session: &SessionData<S>,
+        channel_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let channel_id = channel_id.as_ref();
+        let user_id = user_id.as_ref().to_owned();
+
+        let first_page = match self.pages.first() {
+            Some(page) => page.clone(),
+            None => return Ok(()),
+        };
+
+        let msg = session.http.send_embed(channel_id, first_page).await?;
+
+        // There is nothing to navigate
+        if self.pages.len() == 1 {
+            return Ok(());
+        }
+
+        // Start listening before adding the reactions, that way no reaction is missed
+        let message_id = msg.id.clone();
+        let predicate_user_id = user_id.clone();
+        let reactions = session
+            .collectors
+            .register(
+                move |r: &MessageReactionAdd| r.message_id == message_id && r.user_id == predicate_user_id,
+                None,
+            )
+            .await
+            .fuse();
+
+        let result = self.navigate(session, channel_id, &msg.id, &user_id, reactions).await;
+
+        // Clean up the navigation reactions, even if the navigation failed
+        for emoji in &[FIRST, PREVIOUS, NEXT, LAST, STOP] {
+            if let Err(e) = session.http.remove_own_reaction(channel_id, &msg.id, emoji).await {
+                log::warn!("Paginator can't remove its reaction {}: {}", emoji, e);
+            }
+        }
+
+        result
+    }
+
+    /// Add the navigation reactions and change the page with the reactions of the user
+    async fn navigate<S>(
+        &self,
+        session: &SessionData<S>,
+        channel_id: &str,
+        message_id: &str,
+        user_id: &str,
+        mut reactions: impl FusedStream<Item = MessageReactionAdd> + Unpin,
+    ) -> Result<()> {
+        for emoji in &[FIRST, PREVIOUS, NEXT, LAST, STOP] {
+            session.http.add_reaction(channel_id, message_id, emoji).await?;
+        }
+
+        let last = self.pages.len() - 1;
+        let mut current: usize = 0;
+
+        while let Some(reaction) = next_before(&mut reactions, self.timeout).await {
+            let emoji = match reaction.emoji.name {
+                Some(name) => name,
+                None => continue,
+            };
+
+            // Remove the user reaction, so the same button can be used again. It fails in DMs or
+            // without the MANAGE_MESSAGES permission, then the user has to remove it.
+            if let Err(e) = session
+                .http
+                .remove_user_reaction(channel_id, message_id, user_id, &emoji)
+                .await
+            {
+                log::debug!("Paginator can't remove the reaction of the user: {}", e);
+            }
+
+            let next = match button_action(&emoji, current, last) {
+                Some(Action::Page(next)) => next,
+                Some(Action::Stop) => break,
+                None => continue,
+            };
+
+            if next != current {
+                current = next;
+                let edit = MessageEdit::new().embed(self.pages[current].clone());
+                session.http.edit_message(channel_id, message_id, edit).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// What a navigation button does
+#[derive(Debug, PartialEq)]
+enum Action {
+    Page(usize),
+    Stop,
+}
+
+/// Returns the action of the button, or `None` if the emoji isn't a navigation button
+fn button_action(emoji: &str, current: usize, last: usize) -> Option<Action> {
+    let next = if is_button(emoji, FIRST) {
+        0
+    } else if is_button(emoji, PREVIOUS) {
+        current.saturating_sub(1)
+    } else if is_button(emoji, NEXT) {
+        (current + 1).min(last)
+    } else if is_button(emoji, LAST) {
+        last
+    } else if is_button(emoji, STOP) {
+        return Some(Action::Stop);
+    } else {
+        return None;
+    };
+
+    Some(Action::Page(next))
+}
+
+/// Returns the next item of the stream, or `None` if the timeout is reached before
+async fn next_before<T>(stream: &mut (impl FusedStream<Item = T> + Unpin), timeout: Duration) -> Option<T> {
+    let mut idle = runtime::sleep(timeout).fuse();
+
+    select! {
+        item = stream.next() => item,
+        _ = idle => None,
+    }
+}
+
+/// Compares the emojis ignoring the variation selector, discord doesn't always send it
+fn is_button(emoji: &str, button: &str) -> bool {
+    emoji.trim_end_matches('\u{fe0f}') == button.trim_end_matches('\u{fe0f}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    #[test]
+    fn buttons_change_the_page() {
+        assert_eq!(button_action(NEXT, 0, 2), Some(Action::Page(1)));
+        assert_eq!(button_action(NEXT, 2, 2), Some(Action::Page(2)));
+        assert_eq!(button_action(PREVIOUS, 1, 2), Some(Action::Page(0)));
+        assert_eq!(button_action(PREVIOUS, 0, 2), Some(Action::Page(0)));
+        assert_eq!(button_action(FIRST, 2, 2), Some(Action::Page(0)));
+        assert_eq!(button_action(LAST, 0, 2), Some(Action::Page(2)));
+        assert_eq!(button_action(STOP, 1, 2), Some(Action::Stop));
+        assert_eq!(button_action("\u{1f44d}", 1, 2), None);
+    }
+
+    #[test]
+    fn variation_selector_is_ignored() {
+        assert_eq!(button_action("\u{25b6}", 0, 2), Some(Action::Page(1)));
+    }
+
+    #[tokio::test]
+    async fn waits_for_the_next_reaction() {
+        let mut reactions = stream::iter(vec![1, 2]).fuse();
+
+        assert_eq!(next_before(&mut reactions, Duration::from_secs(5)).await, Some(1));
+        assert_eq!(next_before(&mut reactions, Duration::from_secs(5)).await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn stops_when_idle() {
+        let mut reactions = stream::pending::<u32>().fuse();
+
+        let start = std::time::Instant::now();
+        assert_eq!(next_before(&mut reactions, Duration::from_millis(50)).await, None);
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}